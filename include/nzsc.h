@@ -0,0 +1,258 @@
+#ifndef NZSC_H
+#define NZSC_H
+
+/* Generated by cbindgen from src/capi.rs. Don't edit this file by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/*
+ The answer was accepted (though it may have been penalized).
+ */
+#define NZSC_OK 0
+
+/*
+ A pointer argument was null.
+ */
+#define NZSC_ERROR_NULL -1
+
+/*
+ The id doesn't belong to any character, booster, or move.
+ */
+#define NZSC_ERROR_INVALID_ID -2
+
+/*
+ The game is already over.
+ */
+#define NZSC_ERROR_GAME_OVER -3
+
+/*
+ The answer is for a different question than the one being asked.
+ */
+#define NZSC_ERROR_WRONG_ANSWER_TYPE -4
+
+/*
+ The text isn't valid UTF-8.
+ */
+#define NZSC_ERROR_INVALID_UTF8 -5
+
+/*
+ The game is over, so there is no question.
+ */
+#define NZSC_QUESTION_NONE 0
+
+#define NZSC_QUESTION_CHARACTER 1
+
+#define NZSC_QUESTION_BOOSTER 2
+
+#define NZSC_QUESTION_MOVE 3
+
+enum NzscMove
+#if __STDC_VERSION__ >= 202311L
+  : uint8_t
+#endif // __STDC_VERSION__ >= 202311L
+ {
+  NzscMove_Kick = 0,
+  NzscMove_NinjaSword = 1,
+  NzscMove_Nunchucks = 2,
+  NzscMove_ShadowFireball = 3,
+  NzscMove_ShadowSlip = 4,
+  NzscMove_RunInCircles = 5,
+  NzscMove_LightningFastKarateChop = 6,
+  NzscMove_Rampage = 7,
+  NzscMove_Muscle = 8,
+  NzscMove_Zap = 9,
+  NzscMove_Regenerate = 10,
+  NzscMove_Gravedigger = 11,
+  NzscMove_ZombieCorps = 12,
+  NzscMove_Apocalypse = 13,
+  NzscMove_SamuraiSword = 14,
+  NzscMove_Helmet = 15,
+  NzscMove_Smash = 16,
+  NzscMove_Lightning = 17,
+  NzscMove_Earthquake = 18,
+  NzscMove_Twist = 19,
+  NzscMove_Bend = 20,
+  NzscMove_JugglingKnives = 21,
+  NzscMove_AcidSpray = 22,
+  NzscMove_Nose = 23,
+  NzscMove_BackwardsMoustachio = 24,
+  NzscMove_NoseOfTheTaunted = 25,
+  NzscMove_MustacheMash = 26,
+  NzscMove_BigHairyDeal = 27,
+};
+#if __STDC_VERSION__ >= 202311L
+typedef enum NzscMove NzscMove;
+#else
+typedef uint8_t NzscMove;
+#endif // __STDC_VERSION__ >= 202311L
+
+enum NzscCharacter
+#if __STDC_VERSION__ >= 202311L
+  : uint8_t
+#endif // __STDC_VERSION__ >= 202311L
+ {
+  NzscCharacter_Ninja = 0,
+  NzscCharacter_Zombie = 1,
+  NzscCharacter_Samurai = 2,
+  NzscCharacter_Clown = 3,
+};
+#if __STDC_VERSION__ >= 202311L
+typedef enum NzscCharacter NzscCharacter;
+#else
+typedef uint8_t NzscCharacter;
+#endif // __STDC_VERSION__ >= 202311L
+
+enum NzscBooster
+#if __STDC_VERSION__ >= 202311L
+  : uint8_t
+#endif // __STDC_VERSION__ >= 202311L
+ {
+  NzscBooster_Shadow = 0,
+  NzscBooster_Speedy = 1,
+  NzscBooster_Regenerative = 2,
+  NzscBooster_ZombieCorps = 3,
+  NzscBooster_Atlas = 4,
+  NzscBooster_Strong = 5,
+  NzscBooster_Backwards = 6,
+  NzscBooster_Moustachio = 7,
+  NzscBooster_None = 8,
+};
+#if __STDC_VERSION__ >= 202311L
+typedef enum NzscBooster NzscBooster;
+#else
+typedef uint8_t NzscBooster;
+#endif // __STDC_VERSION__ >= 202311L
+
+/*
+ A game against a computer that chooses randomly, with the standard rules.
+ */
+typedef struct NZSCGame NZSCGame;
+
+/*
+ Every move, in order.
+ */
+#define NZSC_MOVES { NzscMove_Kick, NzscMove_NinjaSword, NzscMove_Nunchucks, NzscMove_ShadowFireball, NzscMove_ShadowSlip, NzscMove_RunInCircles, NzscMove_LightningFastKarateChop, NzscMove_Rampage, NzscMove_Muscle, NzscMove_Zap, NzscMove_Regenerate, NzscMove_Gravedigger, NzscMove_ZombieCorps, NzscMove_Apocalypse, NzscMove_SamuraiSword, NzscMove_Helmet, NzscMove_Smash, NzscMove_Lightning, NzscMove_Earthquake, NzscMove_Twist, NzscMove_Bend, NzscMove_JugglingKnives, NzscMove_AcidSpray, NzscMove_Nose, NzscMove_BackwardsMoustachio, NzscMove_NoseOfTheTaunted, NzscMove_MustacheMash, NzscMove_BigHairyDeal, }
+
+#define NZSC_SINGLE_USE_MOVES { NzscMove_Zap, NzscMove_Regenerate, NzscMove_AcidSpray, }
+
+#define NZSC_DESTRUCTIVE_MOVES { NzscMove_Zap, NzscMove_AcidSpray, }
+
+/*
+ Every character, in order.
+ */
+#define NZSC_CHARACTERS { NzscCharacter_Ninja, NzscCharacter_Zombie, NzscCharacter_Samurai, NzscCharacter_Clown, }
+
+/*
+ Every booster, in order.
+ */
+#define NZSC_BOOSTERS { NzscBooster_Shadow, NzscBooster_Speedy, NzscBooster_Regenerative, NzscBooster_ZombieCorps, NzscBooster_Atlas, NzscBooster_Strong, NzscBooster_Backwards, NzscBooster_Moustachio, NzscBooster_None, }
+
+/*
+ Creates a game. Free it with `nzsc_game_free`.
+ */
+struct NZSCGame *nzsc_game_new(uint32_t seed);
+
+/*
+ Frees a game created with `nzsc_game_new`.
+
+ # Safety
+
+ `game` must be null or a pointer returned by `nzsc_game_new` that hasn't been freed.
+ */
+void nzsc_game_free(struct NZSCGame *game);
+
+/*
+ Chooses the character with id `character_id`.
+
+ Returns `NZSC_OK` or one of the `NZSC_ERROR_*` codes.
+
+ # Safety
+
+ `game` must be null or a live pointer returned by `nzsc_game_new`.
+ */
+int32_t nzsc_game_choose_character(struct NZSCGame *game, uint8_t character_id);
+
+/*
+ Chooses the booster with id `booster_id`.
+
+ Returns `NZSC_OK` or one of the `NZSC_ERROR_*` codes.
+
+ # Safety
+
+ `game` must be null or a live pointer returned by `nzsc_game_new`.
+ */
+int32_t nzsc_game_choose_booster(struct NZSCGame *game, uint8_t booster_id);
+
+/*
+ Chooses the move with id `move_id`.
+
+ Returns `NZSC_OK` or one of the `NZSC_ERROR_*` codes.
+
+ # Safety
+
+ `game` must be null or a live pointer returned by `nzsc_game_new`.
+ */
+int32_t nzsc_game_choose_move(struct NZSCGame *game, uint8_t move_id);
+
+/*
+ Answers the current question with text typed by the user (e.g., "Ninja Sword").
+
+ Returns `NZSC_OK` or one of the `NZSC_ERROR_*` codes.
+
+ # Safety
+
+ `game` must be null or a live pointer returned by `nzsc_game_new`,
+ and `text` must be null or a null-terminated string.
+ */
+int32_t nzsc_game_answer_text(struct NZSCGame *game, const char *text);
+
+/*
+ The kind of question currently being asked (one of the `NZSC_QUESTION_*` codes).
+
+ Returns `NZSC_ERROR_NULL` if `game` is null.
+
+ # Safety
+
+ `game` must be null or a live pointer returned by `nzsc_game_new`.
+ */
+int32_t nzsc_game_question_kind(const struct NZSCGame *game);
+
+/*
+ Writes the ids of the options in the current question to `ids`, writing at most `capacity` ids.
+
+ Returns the number of options (which may be more than `capacity`), or 0 if `game` is null.
+
+ # Safety
+
+ `game` must be null or a live pointer returned by `nzsc_game_new`,
+ and `ids` must be null or point to at least `capacity` bytes.
+ */
+uintptr_t nzsc_game_available_options(const struct NZSCGame *game,
+                                      uint8_t *ids,
+                                      uintptr_t capacity);
+
+/*
+ The question and notifications from the last accepted answer, as JSON
+ (in the same format as `Output`'s `serde` representation).
+
+ Returns null if `game` is null. Free the string with `nzsc_string_free`.
+
+ # Safety
+
+ `game` must be null or a live pointer returned by `nzsc_game_new`.
+ */
+char *nzsc_game_output_json(const struct NZSCGame *game);
+
+/*
+ Frees a string returned by this library.
+
+ # Safety
+
+ `string` must be null or a pointer returned by this library that hasn't been freed.
+ */
+void nzsc_string_free(char *string);
+
+#endif  /* NZSC_H */
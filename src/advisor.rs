@@ -0,0 +1,121 @@
+//! Suggests good choices for the human, e.g., for highlighting them in a tutorial.
+//!
+//! Choices are scored against a computer that chooses uniformly at random (like `RandomStrategy`),
+//! using the same search as `HardStrategy`.
+
+use std::cmp::Ordering;
+
+use super::single_player_game::Phase;
+use super::players::{
+    BoosterlessPlayer,
+    Player,
+};
+use super::characters::Character;
+use super::boosters::Booster;
+use super::moves::Move;
+use super::outcomes;
+use super::strategies::hard;
+use super::rules::Rules;
+
+/// The expected point differential (the human's points minus the computer's) a choice leads to.
+pub type Score = f64;
+
+/// How many rounds ahead moves are searched.
+const MOVE_DEPTH: u8 = 3;
+
+/// Ranks the human's available moves, best first.
+///
+/// Scores assume the computer chooses uniformly at random, so they can mislead against other strategies.
+///
+/// Destroyed moves and moves the human can't repeat aren't included,
+/// and the computer's replies are limited the same way.
+/// Returns nothing unless moves are being chosen.
+pub fn advise(phase: &Phase, rules: &Rules) -> Vec<(Move, Score)> {
+    match *phase {
+        Phase::MoveChoosing { ref human, ref computer } => {
+            ranked(hard::evaluate_moves(human, computer, rules, MOVE_DEPTH))
+        },
+        _ => vec![],
+    }
+}
+
+/// Ranks the human's boosters, best first, by how well their moves do in the first round
+/// against each booster the computer might choose.
+///
+/// Returns nothing unless boosters are being chosen.
+pub fn advise_booster(phase: &Phase, rules: &Rules) -> Vec<(Booster, Score)> {
+    match *phase {
+        Phase::BoosterChoosing { ref human, ref computer } => ranked(evaluate_boosters(human, computer, rules)),
+        _ => vec![],
+    }
+}
+
+/// Ranks the human's available characters, best first,
+/// by the headstart they get plus how well their best booster does (see `advise_booster`)
+/// against each character the computer might choose.
+///
+/// Choosing the same character as the computer scores 0, since you both choose again.
+/// Returns nothing unless characters are being chosen.
+pub fn advise_character(phase: &Phase, rules: &Rules) -> Vec<(Character, Score)> {
+    let (human, computer) = match *phase {
+        Phase::CharacterChoosing { ref human, ref computer } => (human, computer),
+        _ => return vec![],
+    };
+    let computer_characters = computer.available_characters_with_rules(rules);
+
+    let evaluations = human.available_characters_with_rules(rules).into_iter()
+        .map(|character| {
+            let total: Score = computer_characters.iter()
+                .filter(|&&computer_character| computer_character != character)
+                .map(|&computer_character| {
+                    let headstart = outcomes::get_headstart(character, computer_character);
+                    let best_booster = evaluate_boosters(
+                        &human.to_boosterless_player(character),
+                        &computer.to_boosterless_player(computer_character),
+                        rules,
+                    ).into_iter()
+                        .map(|(_, score)| score)
+                        .fold(Score::NEG_INFINITY, Score::max);
+
+                    headstart.0 as Score - headstart.1 as Score + best_booster
+                })
+                .sum();
+
+            (character, total / computer_characters.len() as Score)
+        })
+        .collect();
+
+    ranked(evaluations)
+}
+
+/// Scores each of `human`'s boosters by the best first-round move score,
+/// averaged over the boosters the computer might choose (it never chooses `Booster::None`).
+fn evaluate_boosters(human: &BoosterlessPlayer, computer: &BoosterlessPlayer, rules: &Rules) -> Vec<(Booster, Score)> {
+    let computer_boosters: Vec<Booster> = computer.available_boosters().into_iter()
+        .filter(|&booster| booster != Booster::None)
+        .collect();
+
+    human.available_boosters().into_iter()
+        .map(|booster| {
+            let human = human.to_player(booster);
+            let total: Score = computer_boosters.iter()
+                .map(|&computer_booster| best_move_score(&human, &computer.to_player(computer_booster), rules))
+                .sum();
+
+            (booster, total / computer_boosters.len() as Score)
+        })
+        .collect()
+}
+
+fn best_move_score(human: &Player, computer: &Player, rules: &Rules) -> Score {
+    hard::evaluate_moves(human, computer, rules, 1).into_iter()
+        .map(|(_, score)| score)
+        .fold(Score::NEG_INFINITY, Score::max)
+}
+
+/// Sorts best first, keeping the original order of equally good options.
+fn ranked<T>(mut evaluations: Vec<(T, Score)>) -> Vec<(T, Score)> {
+    evaluations.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal));
+
+    evaluations
+}
@@ -0,0 +1,306 @@
+//! Analyzes the outcome tables, so designers can see which moves, boosters, and characters are over- or underpowered.
+//!
+//! Everything here is about a single round: single-use and destructive moves are treated like any other move.
+
+use std::fmt::Write;
+
+use super::moves::{
+    Move,
+    MOVES,
+};
+use super::characters::{
+    Character,
+    CHARACTERS,
+};
+use super::boosters::Booster;
+use super::outcomes;
+
+/// How a move does against every other move.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MoveRecord {
+    pub selected_move: Move,
+    /// Only this move gets a point.
+    pub wins: u8,
+    /// Only the other move gets a point.
+    pub losses: u8,
+    /// Neither move gets a point.
+    pub ties: u8,
+    /// Both moves get a point.
+    pub trades: u8,
+}
+
+impl MoveRecord {
+    /// Wins minus losses.
+    pub fn net(&self) -> i16 {
+        self.wins as i16 - self.losses as i16
+    }
+}
+
+/// How a character does with a booster, against characters (with their boosters) it could face.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LoadoutStrength {
+    pub character: Character,
+    pub booster: Booster,
+    /// The expected point differential per round
+    /// when both players choose uniformly among their moves and the opponent chooses a random loadout.
+    pub advantage: f64,
+}
+
+/// Everything `analyze` finds.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BalanceReport {
+    /// In the same order as `MOVES`.
+    pub moves: Vec<MoveRecord>,
+    /// Pairs `(a, b)` where `a` does at least as well as `b` against every move, and better against some.
+    pub dominance: Vec<(Move, Move)>,
+    /// Triples where the first move beats the second, the second beats the third, and the third beats the first.
+    /// Each cycle is listed once, starting with its move that comes first in `MOVES`.
+    pub cycles: Vec<[Move; 3]>,
+    pub loadouts: Vec<LoadoutStrength>,
+    /// Each character's average advantage over its boosters.
+    pub characters: Vec<(Character, f64)>,
+    /// Pairs `(a, b)` where `a` gets a headstart against `b`.
+    pub headstarts: Vec<(Character, Character)>,
+}
+
+pub fn analyze() -> BalanceReport {
+    let moves = MOVES.iter()
+        .map(|&selected_move| move_record(selected_move))
+        .collect();
+
+    let mut dominance = vec![];
+    for &a in MOVES.iter() {
+        for &b in MOVES.iter() {
+            if a != b && dominates(a, b) {
+                dominance.push((a, b));
+            }
+        }
+    }
+
+    let mut cycles = vec![];
+    for (i, &a) in MOVES.iter().enumerate() {
+        for &b in &MOVES[i + 1..] {
+            for &c in &MOVES[i + 1..] {
+                if b != c && beats(a, b) && beats(b, c) && beats(c, a) {
+                    cycles.push([a, b, c]);
+                }
+            }
+        }
+    }
+
+    let loadouts: Vec<LoadoutStrength> = CHARACTERS.iter()
+        .flat_map(|&character| {
+            character.get_boosters().into_iter().map(move |booster| LoadoutStrength {
+                character,
+                booster,
+                advantage: advantage(character, booster),
+            })
+        })
+        .collect();
+
+    let characters = CHARACTERS.iter()
+        .map(|&character| {
+            let advantages: Vec<f64> = loadouts.iter()
+                .filter(|loadout| loadout.character == character)
+                .map(|loadout| loadout.advantage)
+                .collect();
+
+            (character, advantages.iter().sum::<f64>() / advantages.len() as f64)
+        })
+        .collect();
+
+    let mut headstarts = vec![];
+    for &a in CHARACTERS.iter() {
+        for &b in CHARACTERS.iter() {
+            if outcomes::get_headstart(a, b).0 > 0 {
+                headstarts.push((a, b));
+            }
+        }
+    }
+
+    BalanceReport {
+        moves,
+        dominance,
+        cycles,
+        loadouts,
+        characters,
+        headstarts,
+    }
+}
+
+impl BalanceReport {
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = String::new();
+
+        writeln!(markdown, "# NZSC balance report").unwrap();
+
+        writeln!(markdown, "\n## Moves\n").unwrap();
+        writeln!(markdown, "| Move | Wins | Losses | Ties | Trades | Net |").unwrap();
+        writeln!(markdown, "|---|---|---|---|---|---|").unwrap();
+        for record in &self.moves {
+            writeln!(
+                markdown,
+                "| {} | {} | {} | {} | {} | {} |",
+                record.selected_move, record.wins, record.losses, record.ties, record.trades, record.net()
+            ).unwrap();
+        }
+
+        writeln!(markdown, "\n## Dominance\n").unwrap();
+        if self.dominance.is_empty() {
+            writeln!(markdown, "No move dominates another.").unwrap();
+        }
+        for &(a, b) in &self.dominance {
+            writeln!(markdown, "- {} dominates {}", a, b).unwrap();
+        }
+
+        writeln!(markdown, "\n## Cycles\n").unwrap();
+        writeln!(markdown, "{} cycles of three moves.", self.cycles.len()).unwrap();
+        writeln!(markdown, "\n| Move | Cycles |").unwrap();
+        writeln!(markdown, "|---|---|").unwrap();
+        for &selected_move in MOVES.iter() {
+            let count = self.cycles.iter()
+                .filter(|cycle| cycle.contains(&selected_move))
+                .count();
+            writeln!(markdown, "| {} | {} |", selected_move, count).unwrap();
+        }
+
+        writeln!(markdown, "\n## Boosters\n").unwrap();
+        writeln!(markdown, "| Character | Booster | Advantage |").unwrap();
+        writeln!(markdown, "|---|---|---|").unwrap();
+        for loadout in &self.loadouts {
+            writeln!(markdown, "| {} | {} | {:+.3} |", loadout.character, loadout.booster, loadout.advantage).unwrap();
+        }
+
+        writeln!(markdown, "\n## Characters\n").unwrap();
+        writeln!(markdown, "| Character | Advantage |").unwrap();
+        writeln!(markdown, "|---|---|").unwrap();
+        for &(character, advantage) in &self.characters {
+            writeln!(markdown, "| {} | {:+.3} |", character, advantage).unwrap();
+        }
+
+        writeln!(markdown, "\n## Headstarts\n").unwrap();
+        for &(a, b) in &self.headstarts {
+            writeln!(markdown, "- {} gets a headstart against {}", a, b).unwrap();
+        }
+
+        markdown
+    }
+
+    /// The move records, as CSV.
+    pub fn moves_csv(&self) -> String {
+        let mut csv = "move,wins,losses,ties,trades,net\n".to_string();
+
+        for record in &self.moves {
+            writeln!(
+                csv,
+                "{},{},{},{},{},{}",
+                record.selected_move, record.wins, record.losses, record.ties, record.trades, record.net()
+            ).unwrap();
+        }
+
+        csv
+    }
+
+    /// The loadout strengths, as CSV.
+    pub fn boosters_csv(&self) -> String {
+        let mut csv = "character,booster,advantage\n".to_string();
+
+        for loadout in &self.loadouts {
+            writeln!(csv, "{},{},{:.4}", loadout.character, loadout.booster, loadout.advantage).unwrap();
+        }
+
+        csv
+    }
+}
+
+fn move_record(selected_move: Move) -> MoveRecord {
+    let mut record = MoveRecord {
+        selected_move,
+        wins: 0,
+        losses: 0,
+        ties: 0,
+        trades: 0,
+    };
+
+    for &other in MOVES.iter().filter(|&&other| other != selected_move) {
+        match points(selected_move, other) {
+            (1, 0) => record.wins += 1,
+            (0, 1) => record.losses += 1,
+            (0, 0) => record.ties += 1,
+            _ => record.trades += 1,
+        }
+    }
+
+    record
+}
+
+fn points(a: Move, b: Move) -> (u8, u8) {
+    let points = outcomes::get_points(vec![a, b]);
+
+    (points[0], points[1])
+}
+
+fn differential(a: Move, b: Move) -> i8 {
+    let (a_points, b_points) = points(a, b);
+
+    a_points as i8 - b_points as i8
+}
+
+fn beats(a: Move, b: Move) -> bool {
+    points(a, b) == (1, 0)
+}
+
+fn dominates(a: Move, b: Move) -> bool {
+    let mut better_somewhere = false;
+
+    for &other in MOVES.iter() {
+        let (a_differential, b_differential) = (differential(a, other), differential(b, other));
+
+        if a_differential < b_differential {
+            return false;
+        }
+        if a_differential > b_differential {
+            better_somewhere = true;
+        }
+    }
+
+    better_somewhere
+}
+
+fn loadout_moves(character: Character, booster: Booster) -> Vec<Move> {
+    let mut moves = character.get_moves();
+    moves.extend(booster.get_moves());
+
+    moves
+}
+
+fn advantage(character: Character, booster: Booster) -> f64 {
+    let moves = loadout_moves(character, booster);
+    let opponents: Vec<(Booster, Vec<Move>)> = CHARACTERS.iter()
+        .filter(|&&opponent| opponent != character)
+        .flat_map(|&opponent| {
+            opponent.get_boosters().into_iter().map(move |opponent_booster| {
+                (opponent_booster, loadout_moves(opponent, opponent_booster))
+            })
+        })
+        .collect();
+
+    let total: f64 = opponents.iter()
+        .map(|&(opponent_booster, ref opponent_moves)| {
+            let mut sum = 0;
+            for &a in &moves {
+                for &b in opponent_moves {
+                    let (a_points, b_points) = outcomes::get_move_points(a, booster, b, opponent_booster);
+                    sum += a_points as i32 - b_points as i32;
+                }
+            }
+
+            sum as f64 / (moves.len() * opponent_moves.len()) as f64
+        })
+        .sum();
+
+    total / opponents.len() as f64
+}
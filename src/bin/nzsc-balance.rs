@@ -0,0 +1,35 @@
+//! Print a balance report for the standard moves, boosters, and characters.
+//!
+//! Usage: `nzsc-balance [--csv moves|boosters]`
+//!
+//! Without arguments, the report is printed as Markdown.
+
+extern crate nzsc_single_player;
+
+use std::env;
+use std::process;
+
+use nzsc_single_player::balance;
+
+const USAGE: &str = "Usage: nzsc-balance [--csv moves|boosters]";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let report = balance::analyze();
+
+    let output = match args.iter().map(|arg| &arg[..]).collect::<Vec<_>>()[..] {
+        [] => report.to_markdown(),
+        ["--csv", "moves"] => report.moves_csv(),
+        ["--csv", "boosters"] => report.boosters_csv(),
+        ["-h"] | ["--help"] => {
+            println!("{}", USAGE);
+            return;
+        },
+        _ => {
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        },
+    };
+
+    print!("{}", output);
+}
@@ -0,0 +1,101 @@
+//! Serve games over the line-delimited JSON protocol described in the `server` module.
+//!
+//! Usage: `nzsc-server [--tcp <address> | --unix <path>]`
+//!
+//! Without arguments, requests are read from stdin and responses are written to stdout.
+
+extern crate nzsc_single_player;
+
+use std::env;
+use std::fmt;
+use std::io;
+use std::net::TcpListener;
+#[cfg(unix)]
+use std::os::unix::net::UnixListener;
+use std::process;
+
+use nzsc_single_player::server::{
+    self,
+    Server,
+};
+
+const USAGE: &str = "Usage: nzsc-server [--tcp <address> | --unix <path>]";
+
+enum Transport {
+    Stdio,
+    Tcp(String),
+    Unix(String),
+}
+
+fn main() {
+    let transport = match parse_transport(env::args().skip(1)) {
+        Ok(transport) => transport,
+        Err(message) => {
+            eprintln!("{}\n{}", message, USAGE);
+            process::exit(2);
+        },
+    };
+
+    let mut server = Server::default();
+
+    match transport {
+        Transport::Stdio => {
+            let stdin = io::stdin();
+            let stdout = io::stdout();
+
+            if let Err(error) = server::serve(&mut server, stdin.lock(), stdout.lock()) {
+                eprintln!("{}", error);
+                process::exit(1);
+            }
+        },
+        Transport::Tcp(address) => {
+            let listener = TcpListener::bind(&address[..]).unwrap_or_else(|error| fail(error));
+            eprintln!("Listening on {}", address);
+
+            server::serve_tcp(&mut server, listener);
+        },
+        Transport::Unix(path) => serve_unix(&mut server, &path),
+    }
+}
+
+fn parse_transport<I: Iterator<Item = String>>(mut args: I) -> Result<Transport, String> {
+    let mut transport = Transport::Stdio;
+
+    while let Some(arg) = args.next() {
+        match &arg[..] {
+            "--tcp" => {
+                let address = args.next().ok_or_else(|| "Missing value for --tcp.".to_string())?;
+                transport = Transport::Tcp(address);
+            },
+            "--unix" => {
+                let path = args.next().ok_or_else(|| "Missing value for --unix.".to_string())?;
+                transport = Transport::Unix(path);
+            },
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                process::exit(0);
+            },
+            _ => return Err(format!("Unknown argument: {}", arg)),
+        }
+    }
+
+    Ok(transport)
+}
+
+#[cfg(unix)]
+fn serve_unix(server: &mut Server, path: &str) {
+    let listener = UnixListener::bind(path).unwrap_or_else(|error| fail(error));
+    eprintln!("Listening on {}", path);
+
+    server::serve_unix(server, listener);
+}
+
+#[cfg(not(unix))]
+fn serve_unix(_server: &mut Server, _path: &str) {
+    fail("Unix domain sockets aren't supported on this platform.");
+}
+
+fn fail<E: fmt::Display>(error: E) -> ! {
+    eprintln!("{}", error);
+    process::exit(1);
+}
@@ -0,0 +1,145 @@
+//! Play NZSC against the computer on the command line.
+//!
+//! Usage: `nzsc [--seed <seed>]`
+
+extern crate nzsc_single_player;
+
+use std::env;
+use std::io::{
+    self,
+    BufRead,
+    Write,
+};
+use std::process;
+
+use nzsc_single_player::single_player_game::SinglePlayerNZSCGame;
+use nzsc_single_player::rules::Rules;
+use nzsc_single_player::prfg::PseudorandomFloatGenerator;
+use nzsc_single_player::io::{
+    Answer,
+    CharacterSelection,
+    BoosterSelection,
+    MoveSelection,
+    Notification,
+};
+
+const USAGE: &str = "Usage: nzsc [--seed <seed>]";
+
+fn main() {
+    let seed = match parse_seed(env::args().skip(1)) {
+        Ok(seed) => seed,
+        Err(message) => {
+            eprintln!("{}\n{}", message, USAGE);
+            process::exit(2);
+        },
+    };
+
+    let mut game = SinglePlayerNZSCGame::new(seed, Rules::standard());
+    let mut output = game.current_output();
+
+    println!("Welcome to NZSC! (seed: {})", seed);
+
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+
+    loop {
+        for notification in &output.notifications {
+            println!("{}", notification);
+        }
+
+        let question = match output.question {
+            Some(question) => question,
+            None => break,
+        };
+
+        println!();
+        print!("{} ", question);
+        io::stdout().flush().expect("Failed to write to stdout");
+
+        let line = match lines.next() {
+            Some(Ok(line)) => line,
+            // The player quit (e.g., with Ctrl-D).
+            _ => {
+                println!();
+                println!("Goodbye!");
+                return;
+            },
+        };
+
+        let mut answer = question.parse_answer(&line);
+        if is_nonexistent(&answer) {
+            if let Some(suggestion) = question.did_you_mean(&line) {
+                print!("Did you mean {}? (y/n) ", suggestion.name);
+                io::stdout().flush().expect("Failed to write to stdout");
+
+                if let Some(Ok(reply)) = lines.next() {
+                    if let "y" | "yes" = &reply.trim().to_lowercase()[..] {
+                        answer = suggestion.answer;
+                    }
+                }
+            }
+        }
+
+        output = game.next(answer)
+            .expect("Answers are always parsed for the current question");
+    }
+
+    print_summary(&game);
+}
+
+fn parse_seed<I: Iterator<Item = String>>(mut args: I) -> Result<u32, String> {
+    let mut seed = None;
+
+    while let Some(arg) = args.next() {
+        match &arg[..] {
+            "--seed" => {
+                let value = args.next().ok_or_else(|| "Missing value for --seed.".to_string())?;
+                match value.parse() {
+                    // The generator gets stuck at zero.
+                    Ok(0) => return Err("The seed can't be 0.".to_string()),
+                    Ok(value) => seed = Some(value),
+                    Err(_) => return Err(format!("Invalid seed: {}", value)),
+                }
+            },
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                process::exit(0);
+            },
+            _ => return Err(format!("Unknown argument: {}", arg)),
+        }
+    }
+
+    Ok(seed.unwrap_or_else(PseudorandomFloatGenerator::clock_seed))
+}
+
+fn is_nonexistent(answer: &Answer) -> bool {
+    matches!(
+        *answer,
+        Answer::CharacterSelection(CharacterSelection::Nonexistent(_))
+            | Answer::BoosterSelection(BoosterSelection::Nonexistent(_))
+            | Answer::MoveSelection(MoveSelection::Nonexistent(_))
+    )
+}
+
+fn print_summary(game: &SinglePlayerNZSCGame) {
+    let output = game.current_output();
+    let (human_points, computer_points) = match output.notifications.first() {
+        Some(&Notification::GameOver { human_points, computer_points }) => (human_points, computer_points),
+        _ => return,
+    };
+
+    let rounds = game.history().iter()
+        .filter(|entry| entry.notifications.iter().any(|n| {
+            matches!(*n, Notification::MoveSelectionAndOutcome { .. })
+        }))
+        .count();
+    let penalties = game.history().iter()
+        .filter(|entry| entry.computer_choice.is_none())
+        .count();
+
+    println!();
+    println!("Final score: {}-{}", human_points, computer_points);
+    println!("Moves played: {}", rounds);
+    println!("Penalties: {}", penalties);
+    println!("Seed: {}", game.seed());
+}
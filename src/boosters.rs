@@ -3,20 +3,32 @@ use std::fmt;
 use super::helpers;
 use super::moves::Move;
 
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "python", pyo3::pyclass(module = "nzsc_single_player", eq, eq_int, hash, frozen, from_py_object))]
+#[repr(u8)]
 pub enum Booster {
-    Shadow,
-    Speedy,
-    Regenerative,
-    ZombieCorps,
-    Atlas,
-    Strong,
-    Backwards,
-    Moustachio,
-    None
+    Shadow = 0,
+    Speedy = 1,
+    Regenerative = 2,
+    ZombieCorps = 3,
+    Atlas = 4,
+    Strong = 5,
+    Backwards = 6,
+    Moustachio = 7,
+    None = 8
 }
 
 impl Booster {
+    pub fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    /// The inverse of `to_u8`.
+    pub fn from_u8(id: u8) -> Option<Booster> {
+        BOOSTERS.get(id as usize).cloned()
+    }
+
     pub fn get_moves(self) -> Vec<Move> {
         match self {
             Booster::Shadow => vec![
@@ -77,18 +89,31 @@ impl FromStr for Booster {
 
 impl fmt::Display for Booster {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let string = match self {
-            &Booster::Shadow => "Shadow",
-            &Booster::Speedy => "Speedy",
-            &Booster::Regenerative => "Regenerative",
-            &Booster::ZombieCorps => "Zombie Corps",
-            &Booster::Atlas => "Atlas",
-            &Booster::Strong => "Strong",
-            &Booster::Backwards => "Backwards",
-            &Booster::Moustachio => "Moustachio",
-            &Booster::None => "No Booster"
+        let string = match *self {
+            Booster::Shadow => "Shadow",
+            Booster::Speedy => "Speedy",
+            Booster::Regenerative => "Regenerative",
+            Booster::ZombieCorps => "Zombie Corps",
+            Booster::Atlas => "Atlas",
+            Booster::Strong => "Strong",
+            Booster::Backwards => "Backwards",
+            Booster::Moustachio => "Moustachio",
+            Booster::None => "No Booster"
         };
 
         write!(f, "{}", string)
     }
 }
+
+/// Every booster, in order.
+pub const BOOSTERS: [Booster; 9] = [
+    Booster::Shadow,
+    Booster::Speedy,
+    Booster::Regenerative,
+    Booster::ZombieCorps,
+    Booster::Atlas,
+    Booster::Strong,
+    Booster::Backwards,
+    Booster::Moustachio,
+    Booster::None
+];
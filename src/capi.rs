@@ -0,0 +1,259 @@
+//! A C interface for embedding the game in native apps.
+//!
+//! Games are opaque `NZSCGame` handles created with `nzsc_game_new` and freed with `nzsc_game_free`.
+//! Characters, boosters, and moves are identified by their `to_u8` ids,
+//! which the header lists as the `NzscCharacter_*`, `NzscBooster_*`, and `NzscMove_*` enum values.
+//! The header is `include/nzsc.h`, which is generated with `cbindgen --config cbindgen.toml --output include/nzsc.h`.
+
+use std::ffi::{
+    CStr,
+    CString,
+};
+use std::os::raw::c_char;
+use std::ptr;
+
+use serde_json;
+
+use super::single_player_game::SinglePlayerNZSCGame;
+use super::rules::Rules;
+use super::characters::Character;
+use super::boosters::Booster;
+use super::moves::Move;
+use super::errors::AnswerError;
+use super::io;
+
+/// The answer was accepted (though it may have been penalized).
+pub const NZSC_OK: i32 = 0;
+/// A pointer argument was null.
+pub const NZSC_ERROR_NULL: i32 = -1;
+/// The id doesn't belong to any character, booster, or move.
+pub const NZSC_ERROR_INVALID_ID: i32 = -2;
+/// The game is already over.
+pub const NZSC_ERROR_GAME_OVER: i32 = -3;
+/// The answer is for a different question than the one being asked.
+pub const NZSC_ERROR_WRONG_ANSWER_TYPE: i32 = -4;
+/// The text isn't valid UTF-8.
+pub const NZSC_ERROR_INVALID_UTF8: i32 = -5;
+
+/// The game is over, so there is no question.
+pub const NZSC_QUESTION_NONE: i32 = 0;
+pub const NZSC_QUESTION_CHARACTER: i32 = 1;
+pub const NZSC_QUESTION_BOOSTER: i32 = 2;
+pub const NZSC_QUESTION_MOVE: i32 = 3;
+
+/// A game against a computer that chooses randomly, with the standard rules.
+pub struct NZSCGame {
+    game: SinglePlayerNZSCGame,
+    /// The output of the last accepted answer (or the current output, if there isn't one).
+    output: io::Output,
+}
+
+/// Creates a game. Free it with `nzsc_game_free`.
+#[no_mangle]
+pub extern "C" fn nzsc_game_new(seed: u32) -> *mut NZSCGame {
+    let game = SinglePlayerNZSCGame::new(seed, Rules::standard());
+    let output = game.current_output();
+
+    Box::into_raw(Box::new(NZSCGame {
+        game,
+        output,
+    }))
+}
+
+/// Frees a game created with `nzsc_game_new`.
+///
+/// # Safety
+///
+/// `game` must be null or a pointer returned by `nzsc_game_new` that hasn't been freed.
+#[no_mangle]
+pub unsafe extern "C" fn nzsc_game_free(game: *mut NZSCGame) {
+    if !game.is_null() {
+        drop(Box::from_raw(game));
+    }
+}
+
+/// Chooses the character with id `character_id`.
+///
+/// Returns `NZSC_OK` or one of the `NZSC_ERROR_*` codes.
+///
+/// # Safety
+///
+/// `game` must be null or a live pointer returned by `nzsc_game_new`.
+#[no_mangle]
+pub unsafe extern "C" fn nzsc_game_choose_character(game: *mut NZSCGame, character_id: u8) -> i32 {
+    if game.is_null() {
+        return NZSC_ERROR_NULL;
+    }
+
+    match Character::from_u8(character_id) {
+        Some(character) => answer(game, io::Answer::CharacterSelection(io::CharacterSelection::Character(character))),
+        None => NZSC_ERROR_INVALID_ID,
+    }
+}
+
+/// Chooses the booster with id `booster_id`.
+///
+/// Returns `NZSC_OK` or one of the `NZSC_ERROR_*` codes.
+///
+/// # Safety
+///
+/// `game` must be null or a live pointer returned by `nzsc_game_new`.
+#[no_mangle]
+pub unsafe extern "C" fn nzsc_game_choose_booster(game: *mut NZSCGame, booster_id: u8) -> i32 {
+    if game.is_null() {
+        return NZSC_ERROR_NULL;
+    }
+
+    match Booster::from_u8(booster_id) {
+        Some(booster) => answer(game, io::Answer::BoosterSelection(io::BoosterSelection::Booster(booster))),
+        None => NZSC_ERROR_INVALID_ID,
+    }
+}
+
+/// Chooses the move with id `move_id`.
+///
+/// Returns `NZSC_OK` or one of the `NZSC_ERROR_*` codes.
+///
+/// # Safety
+///
+/// `game` must be null or a live pointer returned by `nzsc_game_new`.
+#[no_mangle]
+pub unsafe extern "C" fn nzsc_game_choose_move(game: *mut NZSCGame, move_id: u8) -> i32 {
+    if game.is_null() {
+        return NZSC_ERROR_NULL;
+    }
+
+    match Move::from_u8(move_id) {
+        Some(selected_move) => answer(game, io::Answer::MoveSelection(io::MoveSelection::Move(selected_move))),
+        None => NZSC_ERROR_INVALID_ID,
+    }
+}
+
+/// Answers the current question with text typed by the user (e.g., "Ninja Sword").
+///
+/// Returns `NZSC_OK` or one of the `NZSC_ERROR_*` codes.
+///
+/// # Safety
+///
+/// `game` must be null or a live pointer returned by `nzsc_game_new`,
+/// and `text` must be null or a null-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn nzsc_game_answer_text(game: *mut NZSCGame, text: *const c_char) -> i32 {
+    if game.is_null() || text.is_null() {
+        return NZSC_ERROR_NULL;
+    }
+    let text = match CStr::from_ptr(text).to_str() {
+        Ok(text) => text,
+        Err(_) => return NZSC_ERROR_INVALID_UTF8,
+    };
+
+    match (*game).game.current_output().question {
+        Some(question) => answer(game, question.parse_answer(text)),
+        None => NZSC_ERROR_GAME_OVER,
+    }
+}
+
+/// The kind of question currently being asked (one of the `NZSC_QUESTION_*` codes).
+///
+/// Returns `NZSC_ERROR_NULL` if `game` is null.
+///
+/// # Safety
+///
+/// `game` must be null or a live pointer returned by `nzsc_game_new`.
+#[no_mangle]
+pub unsafe extern "C" fn nzsc_game_question_kind(game: *const NZSCGame) -> i32 {
+    if game.is_null() {
+        return NZSC_ERROR_NULL;
+    }
+
+    match (*game).game.current_output().question {
+        Some(io::Question::ChooseCharacter { .. }) => NZSC_QUESTION_CHARACTER,
+        Some(io::Question::ChooseBooster { .. }) => NZSC_QUESTION_BOOSTER,
+        Some(io::Question::ChooseMove { .. }) => NZSC_QUESTION_MOVE,
+        None => NZSC_QUESTION_NONE,
+    }
+}
+
+/// Writes the ids of the options in the current question to `ids`, writing at most `capacity` ids.
+///
+/// Returns the number of options (which may be more than `capacity`), or 0 if `game` is null.
+///
+/// # Safety
+///
+/// `game` must be null or a live pointer returned by `nzsc_game_new`,
+/// and `ids` must be null or point to at least `capacity` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn nzsc_game_available_options(game: *const NZSCGame, ids: *mut u8, capacity: usize) -> usize {
+    if game.is_null() {
+        return 0;
+    }
+
+    let options: Vec<u8> = match (*game).game.current_output().question {
+        Some(io::Question::ChooseCharacter { available_characters }) => {
+            available_characters.iter().map(|c| c.to_u8()).collect()
+        },
+        Some(io::Question::ChooseBooster { available_boosters }) => {
+            available_boosters.iter().map(|b| b.to_u8()).collect()
+        },
+        Some(io::Question::ChooseMove { available_moves }) => {
+            available_moves.iter().map(|m| m.to_u8()).collect()
+        },
+        None => vec![],
+    };
+
+    if !ids.is_null() {
+        let count = options.len().min(capacity);
+        ptr::copy_nonoverlapping(options.as_ptr(), ids, count);
+    }
+
+    options.len()
+}
+
+/// The question and notifications from the last accepted answer, as JSON
+/// (in the same format as `Output`'s `serde` representation).
+///
+/// Returns null if `game` is null. Free the string with `nzsc_string_free`.
+///
+/// # Safety
+///
+/// `game` must be null or a live pointer returned by `nzsc_game_new`.
+#[no_mangle]
+pub unsafe extern "C" fn nzsc_game_output_json(game: *const NZSCGame) -> *mut c_char {
+    if game.is_null() {
+        return ptr::null_mut();
+    }
+
+    let json = serde_json::to_string(&(*game).output).expect("Outputs can always be serialized");
+
+    CString::new(json)
+        .expect("JSON never contains null bytes")
+        .into_raw()
+}
+
+/// Frees a string returned by this library.
+///
+/// # Safety
+///
+/// `string` must be null or a pointer returned by this library that hasn't been freed.
+#[no_mangle]
+pub unsafe extern "C" fn nzsc_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
+
+unsafe fn answer(game: *mut NZSCGame, answer: io::Answer) -> i32 {
+    if game.is_null() {
+        return NZSC_ERROR_NULL;
+    }
+    let game = &mut *game;
+
+    match game.game.next(answer) {
+        Ok(output) => {
+            game.output = output;
+            NZSC_OK
+        },
+        Err(AnswerError::GameOver) => NZSC_ERROR_GAME_OVER,
+        Err(AnswerError::WrongAnswerType { .. }) => NZSC_ERROR_WRONG_ANSWER_TYPE,
+    }
+}
@@ -4,7 +4,10 @@ use super::helpers;
 use super::moves::Move;
 use super::boosters::Booster;
 
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "python", pyo3::pyclass(module = "nzsc_single_player", eq, eq_int, hash, frozen, from_py_object))]
+#[repr(u8)]
 pub enum Character {
     Ninja = 0,
     Zombie = 1,
@@ -17,6 +20,11 @@ impl Character {
         self as u8
     }
 
+    /// The inverse of `to_u8`.
+    pub fn from_u8(id: u8) -> Option<Character> {
+        CHARACTERS.get(id as usize).cloned()
+    }
+
     pub fn get_moves(self) -> Vec<Move> {
         match self {
             Character::Ninja => vec![
@@ -84,13 +92,21 @@ impl FromStr for Character {
 
 impl fmt::Display for Character {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let string = match self {
-            &Character::Ninja => "Ninja",
-            &Character::Zombie => "Zombie",
-            &Character::Samurai => "Samurai",
-            &Character::Clown => "Clown"
+        let string = match *self {
+            Character::Ninja => "Ninja",
+            Character::Zombie => "Zombie",
+            Character::Samurai => "Samurai",
+            Character::Clown => "Clown"
         };
 
         write!(f, "{}", string)
     }
 }
+
+/// Every character, in order.
+pub const CHARACTERS: [Character; 4] = [
+    Character::Ninja,
+    Character::Zombie,
+    Character::Samurai,
+    Character::Clown
+];
@@ -0,0 +1,129 @@
+use std::error::Error;
+use std::fmt;
+
+use super::io::Answer;
+
+/// Why a game couldn't accept an `Answer`.
+///
+/// Unlike penalties, these errors don't change the game.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AnswerError {
+    /// The game is over, so there is no question to answer.
+    GameOver,
+    /// The answer is for a different question than the one being asked
+    /// (e.g., a `MoveSelection` while characters are being chosen).
+    WrongAnswerType {
+        expected: AnswerType,
+        received: AnswerType,
+    },
+}
+
+impl AnswerError {
+    /// The error for receiving `answer` when `expected` is expected
+    /// (or nothing is, if `expected` is `None`).
+    pub fn unexpected(expected: Option<AnswerType>, answer: &Answer) -> AnswerError {
+        match expected {
+            Some(expected) => AnswerError::WrongAnswerType {
+                expected,
+                received: AnswerType::from(answer),
+            },
+            None => AnswerError::GameOver,
+        }
+    }
+}
+
+/// The kind of an `Answer`, without its contents.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AnswerType {
+    CharacterSelection,
+    BoosterSelection,
+    MoveSelection,
+}
+
+impl<'a> From<&'a Answer> for AnswerType {
+    fn from(answer: &'a Answer) -> AnswerType {
+        match *answer {
+            Answer::CharacterSelection(_) => AnswerType::CharacterSelection,
+            Answer::BoosterSelection(_) => AnswerType::BoosterSelection,
+            Answer::MoveSelection(_) => AnswerType::MoveSelection,
+        }
+    }
+}
+
+impl fmt::Display for AnswerType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let string = match *self {
+            AnswerType::CharacterSelection => "character selection",
+            AnswerType::BoosterSelection => "booster selection",
+            AnswerType::MoveSelection => "move selection",
+        };
+
+        write!(f, "{}", string)
+    }
+}
+
+impl fmt::Display for AnswerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            AnswerError::GameOver => write!(f, "The game is already over."),
+            AnswerError::WrongAnswerType { expected, received } => {
+                write!(f, "Expected a {}, but received a {}.", expected, received)
+            },
+        }
+    }
+}
+
+impl Error for AnswerError {}
+
+/// Why an answer couldn't be undone.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum UndoError {
+    /// Undo was disabled (e.g., for a ranked game).
+    Disabled,
+    /// No answers have been accepted since the game started (or since the last answer was undone),
+    /// or `single_player_game::UNDO_LIMIT` answers were already undone.
+    NothingToUndo,
+    /// The computer's strategy can't be saved, so it couldn't be restored.
+    UnsavableStrategy,
+}
+
+impl fmt::Display for UndoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            UndoError::Disabled => write!(f, "Undo is disabled for this game."),
+            UndoError::NothingToUndo => write!(f, "There is nothing to undo."),
+            UndoError::UnsavableStrategy => write!(f, "The computer's strategy can't be saved, so answers can't be undone."),
+        }
+    }
+}
+
+impl Error for UndoError {}
+
+/// Why a set of `Rules` can't be played with.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RulesError {
+    /// `points_to_win` is 0, so the game would be over before it started.
+    NoPointsToWin,
+    /// `tiebreak_points` is at least `points_to_win`, so a tie would never be broken.
+    TiebreakPointsTooHigh {
+        tiebreak_points: u8,
+        points_to_win: u8,
+    },
+}
+
+impl fmt::Display for RulesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RulesError::NoPointsToWin => write!(f, "At least 1 point must be needed to win."),
+            RulesError::TiebreakPointsTooHigh { tiebreak_points, points_to_win } => {
+                write!(f, "Ties must be broken at fewer than {} points, not {}.", points_to_win, tiebreak_points)
+            },
+        }
+    }
+}
+
+impl Error for RulesError {}
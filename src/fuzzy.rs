@@ -0,0 +1,119 @@
+//! Forgiving matching of typed names, for front-ends that would rather ask
+//! "did you mean...?" than penalize a typo.
+//!
+//! Matching is opt-in: `FromStr` and `Question::parse_answer` still only accept exact names.
+
+use std::fmt;
+
+use super::helpers;
+
+/// An option that text might refer to.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Candidate<T> {
+    pub option: T,
+    /// How good the match is. Lower is better, and 0 is an exact match.
+    pub score: u32,
+}
+
+/// Finds the `options` that `text` might refer to, best first.
+///
+/// Text matches an option if it is
+/// 1. the option's name (score 0),
+/// 2. the initials of the option's name, like "lfkc" for Lightning Fast Karate Chop (score 1),
+/// 3. the start of the option's name, like "nun" for Nunchucks (score 2), or
+/// 4. a few typos away from the option's name, like "nunchuck" for Nunchucks (score 2 + typos).
+///
+/// Case, whitespace, and "moustache"/"mustache" spellings are ignored.
+/// Options that tie keep their order from `options`.
+pub fn rank<T: Copy + fmt::Display>(text: &str, options: &[T]) -> Vec<Candidate<T>> {
+    let text = normalize(text);
+    if text.is_empty() {
+        return vec![];
+    }
+
+    let mut candidates: Vec<Candidate<T>> = options.iter()
+        .filter_map(|&option| {
+            score(&text, &option.to_string()).map(|score| Candidate {
+                option,
+                score,
+            })
+        })
+        .collect();
+
+    // Stable, so ties keep their order.
+    candidates.sort_by_key(|candidate| candidate.score);
+
+    candidates
+}
+
+/// The candidate `text` most likely refers to, if there's exactly one best candidate.
+pub fn best_match<T: Copy + fmt::Display>(text: &str, options: &[T]) -> Option<Candidate<T>> {
+    let mut candidates = rank(text, options).into_iter();
+
+    match (candidates.next(), candidates.next()) {
+        (Some(first), Some(second)) if first.score == second.score => None,
+        (first, _) => first,
+    }
+}
+
+fn score(text: &str, name: &str) -> Option<u32> {
+    let normalized_name = normalize(name);
+
+    if text == normalized_name {
+        return Some(0);
+    }
+
+    let initials: String = name.split_whitespace()
+        .filter_map(|word| word.chars().next())
+        .collect::<String>()
+        .to_lowercase();
+    if initials.len() > 1 && text == initials {
+        return Some(1);
+    }
+
+    if text.len() > 1 && normalized_name.starts_with(text) {
+        return Some(2);
+    }
+
+    let typos = edit_distance(text, &normalized_name);
+    if typos <= max_typos(&normalized_name) {
+        return Some(2 + typos as u32);
+    }
+
+    None
+}
+
+fn normalize(s: &str) -> String {
+    helpers::lowercase_no_whitespace(s).replace("moustach", "mustach")
+}
+
+/// Longer names can have more typos.
+fn max_typos(name: &str) -> usize {
+    match name.chars().count() {
+        0..=4 => 1,
+        5..=8 => 2,
+        _ => 3,
+    }
+}
+
+/// The Levenshtein distance between `a` and `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous_row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut row = vec![i + 1];
+
+        for (j, &b_char) in b.iter().enumerate() {
+            let substitution = previous_row[j] + if a_char == b_char { 0 } else { 1 };
+            let insertion = row[j] + 1;
+            let deletion = previous_row[j + 1] + 1;
+
+            row.push(substitution.min(insertion).min(deletion));
+        }
+
+        previous_row = row;
+    }
+
+    previous_row[b.len()]
+}
@@ -0,0 +1,48 @@
+use super::io;
+use super::characters::Character;
+use super::boosters::Booster;
+use super::moves::Move;
+
+/// What the computer chose in response to an `Answer`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ComputerChoice {
+    Character(Character),
+    Booster(Booster),
+    Move(Move),
+}
+
+/// A record of one successful call to `SinglePlayerNZSCGame::next`.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HistoryEntry {
+    pub answer: io::Answer,
+    /// If the answer was penalized, the computer doesn't choose, so this will be `None`.
+    pub computer_choice: Option<ComputerChoice>,
+    pub notifications: Vec<io::Notification>,
+}
+
+impl ComputerChoice {
+    /// Finds what the computer chose from the notifications it led to.
+    pub fn from_notifications(notifications: &[io::Notification]) -> Option<ComputerChoice> {
+        notifications.iter()
+            .filter_map(|notification| {
+                match *notification {
+                    io::Notification::CharacterSelectionAndHeadstart { computer_character, .. } => {
+                        Some(ComputerChoice::Character(computer_character))
+                    },
+                    io::Notification::SameCharacterSelection { both_character } => {
+                        Some(ComputerChoice::Character(both_character))
+                    },
+                    io::Notification::BoosterSelection { computer_booster, .. } => {
+                        Some(ComputerChoice::Booster(computer_booster))
+                    },
+                    io::Notification::MoveSelectionAndOutcome { computer_move, .. } => {
+                        Some(ComputerChoice::Move(computer_move))
+                    },
+                    _ => None,
+                }
+            })
+            .next()
+    }
+}
@@ -3,22 +3,30 @@ use super::super::boosters::Booster;
 use super::super::moves::Move;
 
 /// The answer to a `Question`.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Answer {
     CharacterSelection(CharacterSelection),
     BoosterSelection(BoosterSelection),
     MoveSelection(MoveSelection),
 }
 
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CharacterSelection {
     Character(Character),
     Nonexistent(String),
 }
 
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum BoosterSelection {
     Booster(Booster),
     Nonexistent(String),
 }
 
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum MoveSelection {
     Move(Move),
     Nonexistent(String),
@@ -6,7 +6,10 @@ pub use self::answers::{
     MoveSelection,
 };
 pub use self::output::Output;
-pub use self::questions::Question;
+pub use self::questions::{
+    Question,
+    Suggestion,
+};
 pub use self::notifications::{
     Notification,
     WhoGetsThePoint,
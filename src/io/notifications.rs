@@ -1,8 +1,22 @@
 use super::super::characters::Character;
 use super::super::boosters::Booster;
 use super::super::moves::Move;
+use super::super::rules::Rules;
+use super::super::locale::{
+    Locale,
+    English,
+    Opponent,
+};
+
+use std::fmt;
 
 /// Something the user should know, but doesn't need to answer.
+///
+/// Notifications are always from the point of view of the player receiving them:
+/// `human` fields (and `JustHuman`) refer to that player, and `computer` fields (and `JustComputer`) to their opponent.
+/// In a `TwoPlayerNZSCGame`, the opponent is the other human, and `swap_perspective` turns one player's notification into the other's.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Notification {
     CharacterSelectionAndHeadstart {
         human_character: Character,
@@ -68,8 +82,21 @@ pub enum Notification {
     MoveFromWrongBoosterPenalty {
         attempted_move: Move,
     },
+    /// Follows a penalty the player didn't have enough waits left to pay,
+    /// so they lost their remaining waits and their opponent got a point.
+    NotEnoughWaits,
+
+    /// The opponent was penalized (with `penalty`), so both players must answer again.
+    ///
+    /// Only used in games between two humans.
+    OpponentPenalty {
+        penalty: Box<Notification>,
+    },
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "python", pyo3::pyclass(module = "nzsc_single_player", eq, eq_int, hash, frozen, from_py_object))]
 pub enum WhoGetsThePoint {
     Neither,
     JustComputer,
@@ -77,8 +104,100 @@ pub enum WhoGetsThePoint {
     Both,
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "python", pyo3::pyclass(module = "nzsc_single_player", eq, eq_int, hash, frozen, from_py_object))]
 pub enum WhoGetsTheHeadstart {
     Neither,
     JustComputer,
     JustHuman,
 }
+
+impl Notification {
+    /// Swaps the human's and computer's fields.
+    ///
+    /// In games between two humans, this converts a notification for one player
+    /// into the same notification for the other player.
+    /// Penalty notifications are returned unchanged.
+    pub fn swap_perspective(self) -> Notification {
+        match self {
+            Notification::CharacterSelectionAndHeadstart { human_character, computer_character, who_gets_the_headstart } => {
+                Notification::CharacterSelectionAndHeadstart {
+                    human_character: computer_character,
+                    computer_character: human_character,
+                    who_gets_the_headstart: who_gets_the_headstart.swap_perspective(),
+                }
+            },
+            Notification::BoosterSelection { human_booster, computer_booster } => {
+                Notification::BoosterSelection {
+                    human_booster: computer_booster,
+                    computer_booster: human_booster,
+                }
+            },
+            Notification::MoveSelectionAndOutcome { human_move, computer_move, who_gets_the_point } => {
+                Notification::MoveSelectionAndOutcome {
+                    human_move: computer_move,
+                    computer_move: human_move,
+                    who_gets_the_point: who_gets_the_point.swap_perspective(),
+                }
+            },
+            Notification::ScoreUpdate { human_points, computer_points } => {
+                Notification::ScoreUpdate {
+                    human_points: computer_points,
+                    computer_points: human_points,
+                }
+            },
+            Notification::GameOver { human_points, computer_points } => {
+                Notification::GameOver {
+                    human_points: computer_points,
+                    computer_points: human_points,
+                }
+            },
+            other => other,
+        }
+    }
+}
+
+impl WhoGetsThePoint {
+    pub fn swap_perspective(self) -> WhoGetsThePoint {
+        match self {
+            WhoGetsThePoint::JustComputer => WhoGetsThePoint::JustHuman,
+            WhoGetsThePoint::JustHuman => WhoGetsThePoint::JustComputer,
+            other => other,
+        }
+    }
+}
+
+impl WhoGetsTheHeadstart {
+    pub fn swap_perspective(self) -> WhoGetsTheHeadstart {
+        match self {
+            WhoGetsTheHeadstart::JustComputer => WhoGetsTheHeadstart::JustHuman,
+            WhoGetsTheHeadstart::JustHuman => WhoGetsTheHeadstart::JustComputer,
+            other => other,
+        }
+    }
+}
+
+impl Notification {
+    /// Describes this notification in English, from the human's point of view.
+    ///
+    /// Penalties are explained using the streak limits and wait costs of `rules`.
+    /// For other languages, see `locale::Locale::render_notification`.
+    pub fn render(&self, rules: &Rules) -> String {
+        English.render_notification(self, rules)
+    }
+
+    /// Like `render`, but worded for a game against `opponent` (e.g., `Opponent::Human` in a `TwoPlayerNZSCGame`).
+    pub fn render_against(&self, rules: &Rules, opponent: Opponent) -> String {
+        English.render_notification_against(self, rules, opponent)
+    }
+}
+
+/// Renders the notification with the standard rules.
+///
+/// For games with other rules, use `Notification::render`.
+impl fmt::Display for Notification {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.render(&Rules::standard()))
+    }
+}
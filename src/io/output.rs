@@ -2,7 +2,8 @@ use super::questions::Question;
 use super::notifications::Notification;
 
 /// A `Question` and some `Notification`s.
-
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Output {
     /// If the game is over (and therefore no user input is required), `question` will be `None`.
     pub question: Option<Question>,
@@ -1,12 +1,63 @@
 use super::super::characters::Character;
 use super::super::boosters::Booster;
 use super::super::moves::Move;
+use super::super::fuzzy;
+use super::super::locale::{
+    Locale,
+    English,
+};
+
+use std::fmt;
+use super::answers::{
+    Answer,
+    CharacterSelection,
+    BoosterSelection,
+    MoveSelection,
+};
+
+/// A legal answer the user might have meant, from `Question::suggestions`.
+#[derive(Clone)]
+pub struct Suggestion {
+    /// The name of the character, booster, or move, for asking "did you mean...?"
+    pub name: String,
+    pub answer: Answer,
+    /// How good the match is. Lower is better. See `fuzzy::rank`.
+    pub score: u32,
+}
+
+impl Suggestion {
+    fn character(candidate: fuzzy::Candidate<Character>) -> Suggestion {
+        Suggestion {
+            name: candidate.option.to_string(),
+            answer: Answer::CharacterSelection(CharacterSelection::Character(candidate.option)),
+            score: candidate.score,
+        }
+    }
+
+    fn booster(candidate: fuzzy::Candidate<Booster>) -> Suggestion {
+        Suggestion {
+            name: candidate.option.to_string(),
+            answer: Answer::BoosterSelection(BoosterSelection::Booster(candidate.option)),
+            score: candidate.score,
+        }
+    }
+
+    fn move_suggestion(candidate: fuzzy::Candidate<Move>) -> Suggestion {
+        Suggestion {
+            name: candidate.option.to_string(),
+            answer: Answer::MoveSelection(MoveSelection::Move(candidate.option)),
+            score: candidate.score,
+        }
+    }
+}
 
 /// A request to the user for an `Answer`.
 ///
 /// This is how input is obtained.
 /// Every question is associated with a context (information that helps the user in answering the question).
 /// The context is stored in the variant fields.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Question {
     ChooseCharacter {
         available_characters: Vec<Character>,
@@ -18,3 +69,72 @@ pub enum Question {
         available_moves: Vec<Move>
     },
 }
+
+impl Question {
+    /// Parses text typed by the user into an `Answer` to this question.
+    ///
+    /// Text that doesn't name a character, booster, or move (as appropriate)
+    /// becomes a `Nonexistent` selection, which the game will penalize.
+    pub fn parse_answer(&self, text: &str) -> Answer {
+        let text = text.trim();
+
+        match *self {
+            Question::ChooseCharacter { .. } => Answer::CharacterSelection(
+                text.parse()
+                    .map(CharacterSelection::Character)
+                    .unwrap_or_else(|_| CharacterSelection::Nonexistent(text.to_string()))
+            ),
+            Question::ChooseBooster { .. } => Answer::BoosterSelection(
+                text.parse()
+                    .map(BoosterSelection::Booster)
+                    .unwrap_or_else(|_| BoosterSelection::Nonexistent(text.to_string()))
+            ),
+            Question::ChooseMove { .. } => Answer::MoveSelection(
+                text.parse()
+                    .map(MoveSelection::Move)
+                    .unwrap_or_else(|_| MoveSelection::Nonexistent(text.to_string()))
+            ),
+        }
+    }
+
+    /// The available answers that `text` might refer to, best first.
+    ///
+    /// Only options listed in this question are suggested, so following a suggestion is never penalized.
+    pub fn suggestions(&self, text: &str) -> Vec<Suggestion> {
+        match *self {
+            Question::ChooseCharacter { ref available_characters } => {
+                fuzzy::rank(text, available_characters).into_iter().map(Suggestion::character).collect()
+            },
+            Question::ChooseBooster { ref available_boosters } => {
+                fuzzy::rank(text, available_boosters).into_iter().map(Suggestion::booster).collect()
+            },
+            Question::ChooseMove { ref available_moves } => {
+                fuzzy::rank(text, available_moves).into_iter().map(Suggestion::move_suggestion).collect()
+            },
+        }
+    }
+
+    /// The available answer `text` most likely refers to, if there's exactly one best suggestion.
+    pub fn did_you_mean(&self, text: &str) -> Option<Suggestion> {
+        match *self {
+            Question::ChooseCharacter { ref available_characters } => {
+                fuzzy::best_match(text, available_characters).map(Suggestion::character)
+            },
+            Question::ChooseBooster { ref available_boosters } => {
+                fuzzy::best_match(text, available_boosters).map(Suggestion::booster)
+            },
+            Question::ChooseMove { ref available_moves } => {
+                fuzzy::best_match(text, available_moves).map(Suggestion::move_suggestion)
+            },
+        }
+    }
+}
+
+/// Describes the question in English.
+///
+/// For other languages, see `locale::Locale::render_question`.
+impl fmt::Display for Question {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", English.render_question(self))
+    }
+}
@@ -1,13 +1,51 @@
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
+#[cfg(any(feature = "ruleset", feature = "capi", feature = "server"))]
+extern crate serde_json;
+#[cfg(feature = "wasm")]
+extern crate wasm_bindgen;
+#[cfg(feature = "wasm")]
+extern crate serde_wasm_bindgen;
+#[cfg(feature = "python")]
+extern crate pyo3;
+// PyO3's macros refer to `::core`, which isn't in scope by default in the 2015 edition.
+#[cfg(feature = "python")]
+extern crate core;
+
 pub mod moves;
 pub mod characters;
 pub mod boosters;
 pub mod outcomes;
 pub mod streaks;
 pub mod players;
+pub mod rules;
+pub mod strategies;
+pub mod nash;
 
 pub mod io;
 pub mod prfg;
+pub mod history;
+pub mod errors;
+pub mod fuzzy;
+pub mod locale;
 
 pub mod single_player_game;
+pub mod two_player_game;
+pub mod simulation;
+pub mod balance;
+pub mod advisor;
+pub mod tutorial;
+#[cfg(feature = "ruleset")]
+pub mod ruleset;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "capi")]
+pub mod capi;
+#[cfg(feature = "python")]
+pub mod python;
+#[cfg(feature = "server")]
+pub mod server;
 
 mod helpers;
+mod penalties;
@@ -0,0 +1,97 @@
+use super::{
+    Locale,
+    Message,
+};
+use super::super::characters::Character;
+use super::super::boosters::Booster;
+use super::super::moves::Move;
+
+/// The English catalog, which uses the same names as `Display` and `FromStr`.
+#[derive(Clone, Copy, Default)]
+pub struct English;
+
+impl Locale for English {
+    fn character_name(&self, character: Character) -> String {
+        character.to_string()
+    }
+
+    fn booster_name(&self, booster: Booster) -> String {
+        booster.to_string()
+    }
+
+    fn move_name(&self, selected_move: Move) -> String {
+        selected_move.to_string()
+    }
+
+    fn booster_aliases(&self, booster: Booster) -> Vec<String> {
+        match booster {
+            Booster::None => vec!["None".to_string()],
+            _ => vec![],
+        }
+    }
+
+    fn message(&self, message: Message) -> String {
+        let template = match message {
+            Message::CharacterSelectionAndHeadstart => "You chose {human}, the computer chose {computer}. {headstart}",
+            Message::HeadstartNeither => "Neither of you gets a headstart.",
+            Message::HeadstartHuman => "You get a headstart.",
+            Message::HeadstartComputer => "The computer gets a headstart.",
+            Message::SameCharacterSelection => "You both chose {character}. Choose again.",
+            Message::BoosterSelection => "You chose {human}, the computer chose {computer}.",
+            Message::MoveSelectionAndOutcome => "You chose {human}, the computer chose {computer}. {point}",
+            Message::PointNeither => "Neither of you gets a point.",
+            Message::PointHuman => "You get the point.",
+            Message::PointComputer => "The computer gets the point.",
+            Message::PointBoth => "You both get a point.",
+            Message::ScoreUpdate => "Score: you {human}, computer {computer}.",
+            Message::TiebreakingScoreSetback => "You're tied at {points} points, so you're both set back to {tiebreak_points}.",
+            Message::GameOver => "Game over! {winner} Final score: you {human}, computer {computer}.",
+            Message::HumanWins => "You win!",
+            Message::ComputerWins => "The computer wins.",
+
+            Message::Penalty => "{explanation} That costs {waits}.",
+            Message::OpponentPenalty => "Your opponent chose illegally. {explanation} That costs them {waits}.",
+            Message::NotEnoughWaits => "You don't have enough waits left, so the computer gets a point instead.",
+            Message::OpponentNotEnoughWaits => "Your opponent doesn't have enough waits left, so you get a point instead.",
+            Message::NonexistentCharacter => "There's no character called \"{name}\".",
+            Message::NonexistentBooster => "There's no booster called \"{name}\".",
+            Message::NonexistentMove => "There's no move called \"{name}\".",
+            Message::TooManyTimesInARow => "{name} can't be chosen more than {limit} times in a row.",
+            Message::SingleUse => "{name} can only be used once.",
+            Message::Destroyed => "{name} was destroyed.",
+            Message::FromWrongCharacter => "{name} belongs to another character.",
+            Message::FromWrongBooster => "{name} belongs to a booster that wasn't chosen.",
+            Message::OneWait => "1 wait",
+            Message::ManyWaits => "{waits} waits",
+
+            Message::ChooseCharacter => "Choose a character ({options}):",
+            Message::ChooseBooster => "Choose a booster ({options}):",
+            Message::ChooseMove => "Choose a move ({options}):",
+            Message::ListSeparator => ", ",
+
+            Message::ExplainHeadstart => "{winner} gets a headstart against {loser}, so whoever chose {winner} starts with a point.",
+            Message::ExplainPoint => "{winner} beats {loser}.",
+            Message::ExplainNoPoint => "Neither {human} nor {computer} beats the other.",
+            Message::ExplainBothPoints => "{human} and {computer} beat each other.",
+            Message::ExplainYourMoveDestroyed => "The computer's {destroyer} destroyed your {name}, so you can't choose it for the rest of the game.",
+            Message::ExplainComputerMoveDestroyed => "Your {destroyer} destroyed the computer's {name}, so it can't choose it for the rest of the game.",
+            Message::ExplainYourSingleUse => "{name} can only be used once, so you can't choose it again.",
+            Message::ExplainComputerSingleUse => "{name} can only be used once, so the computer can't choose it again.",
+            Message::ExplainYourStreakLimit => "You've chosen {name} {times} times in a row, so you can't choose it next time.",
+            Message::ExplainComputerStreakLimit => "The computer has chosen {name} {times} times in a row, so it can't choose it next time.",
+            Message::ExplainWaits => "Illegal choices cost waits, and you have {waits} left. Once you run out, each illegal choice gives the computer a point instead.",
+
+            Message::TwoPlayerCharacterSelectionAndHeadstart => "You chose {human}, your opponent chose {computer}. {headstart}",
+            Message::TwoPlayerHeadstartOpponent => "Your opponent gets a headstart.",
+            Message::TwoPlayerBoosterSelection => "You chose {human}, your opponent chose {computer}.",
+            Message::TwoPlayerMoveSelectionAndOutcome => "You chose {human}, your opponent chose {computer}. {point}",
+            Message::TwoPlayerPointOpponent => "Your opponent gets the point.",
+            Message::TwoPlayerScoreUpdate => "Score: you {human}, your opponent {computer}.",
+            Message::TwoPlayerGameOver => "Game over! {winner} Final score: you {human}, your opponent {computer}.",
+            Message::TwoPlayerOpponentWins => "Your opponent wins.",
+            Message::TwoPlayerNotEnoughWaits => "You don't have enough waits left, so your opponent gets a point instead.",
+        };
+
+        template.to_string()
+    }
+}
@@ -0,0 +1,451 @@
+//! Names, messages, and accepted input in different languages.
+//!
+//! To add a language, implement `Locale`.
+//! Only names, aliases, and message templates have to be provided;
+//! parsing and rendering are built on top of them.
+
+pub use self::english::English;
+
+use super::helpers;
+use super::rules::Rules;
+use super::characters::{
+    Character,
+    CHARACTERS,
+};
+use super::boosters::{
+    Booster,
+    BOOSTERS,
+};
+use super::moves::{
+    Move,
+    MOVES,
+};
+use super::io::{
+    Answer,
+    CharacterSelection,
+    BoosterSelection,
+    MoveSelection,
+    Question,
+    Notification,
+    WhoGetsThePoint,
+    WhoGetsTheHeadstart,
+};
+
+mod english;
+
+/// Who the player receiving a notification is playing against, which changes how some messages are worded.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Opponent {
+    /// A single-player game.
+    Computer,
+    /// A `TwoPlayerNZSCGame`.
+    Human,
+}
+
+/// A message template in a `Locale`'s catalog.
+///
+/// Templates contain placeholders (e.g., `{human}`), which are documented on each variant.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Message {
+    /// `{human}`, `{computer}`, `{headstart}` (one of the `Headstart*` messages)
+    CharacterSelectionAndHeadstart,
+    HeadstartNeither,
+    HeadstartHuman,
+    HeadstartComputer,
+    /// `{character}`
+    SameCharacterSelection,
+    /// `{human}`, `{computer}`
+    BoosterSelection,
+    /// `{human}`, `{computer}`, `{point}` (one of the `Point*` messages)
+    MoveSelectionAndOutcome,
+    PointNeither,
+    PointHuman,
+    PointComputer,
+    PointBoth,
+    /// `{human}`, `{computer}`
+    ScoreUpdate,
+    /// `{points}`, `{tiebreak_points}`
+    TiebreakingScoreSetback,
+    /// `{winner}` (`HumanWins` or `ComputerWins`), `{human}`, `{computer}`
+    GameOver,
+    HumanWins,
+    ComputerWins,
+
+    /// `{explanation}` (one of the messages below), `{waits}` (`OneWait` or `ManyWaits`)
+    Penalty,
+    /// `{explanation}`, `{waits}`
+    OpponentPenalty,
+    /// Follows `Penalty` when the human couldn't pay.
+    NotEnoughWaits,
+    /// Follows `OpponentPenalty` when the opponent couldn't pay.
+    OpponentNotEnoughWaits,
+    /// `{name}`
+    NonexistentCharacter,
+    /// `{name}`
+    NonexistentBooster,
+    /// `{name}`
+    NonexistentMove,
+    /// `{name}`, `{limit}`
+    TooManyTimesInARow,
+    /// `{name}`
+    SingleUse,
+    /// `{name}`
+    Destroyed,
+    /// `{name}`
+    FromWrongCharacter,
+    /// `{name}`
+    FromWrongBooster,
+    OneWait,
+    /// `{waits}`
+    ManyWaits,
+
+    /// `{options}` (the available characters, joined by `ListSeparator`)
+    ChooseCharacter,
+    /// `{options}`
+    ChooseBooster,
+    /// `{options}`
+    ChooseMove,
+    ListSeparator,
+
+    // Tutorial explanations, rendered by `tutorial::Explanation::render`.
+    /// `{winner}`, `{loser}` (characters)
+    ExplainHeadstart,
+    /// `{winner}`, `{loser}` (moves)
+    ExplainPoint,
+    /// `{human}`, `{computer}`
+    ExplainNoPoint,
+    /// `{human}`, `{computer}`
+    ExplainBothPoints,
+    /// `{destroyer}`, `{name}`
+    ExplainYourMoveDestroyed,
+    /// `{destroyer}`, `{name}`
+    ExplainComputerMoveDestroyed,
+    /// `{name}`
+    ExplainYourSingleUse,
+    /// `{name}`
+    ExplainComputerSingleUse,
+    /// `{name}`, `{times}`
+    ExplainYourStreakLimit,
+    /// `{name}`, `{times}`
+    ExplainComputerStreakLimit,
+    /// `{waits}` (`OneWait` or `ManyWaits`)
+    ExplainWaits,
+
+    // Used instead of the messages above when the opponent is another human (see `Opponent`).
+    /// `{human}`, `{computer}`, `{headstart}`
+    TwoPlayerCharacterSelectionAndHeadstart,
+    TwoPlayerHeadstartOpponent,
+    /// `{human}`, `{computer}`
+    TwoPlayerBoosterSelection,
+    /// `{human}`, `{computer}`, `{point}`
+    TwoPlayerMoveSelectionAndOutcome,
+    TwoPlayerPointOpponent,
+    /// `{human}`, `{computer}`
+    TwoPlayerScoreUpdate,
+    /// `{winner}`, `{human}`, `{computer}`
+    TwoPlayerGameOver,
+    TwoPlayerOpponentWins,
+    TwoPlayerNotEnoughWaits,
+}
+
+impl Message {
+    /// The message to use instead of this one against `opponent`.
+    pub fn against(self, opponent: Opponent) -> Message {
+        match (opponent, self) {
+            (Opponent::Computer, message) => message,
+            (Opponent::Human, Message::CharacterSelectionAndHeadstart) => Message::TwoPlayerCharacterSelectionAndHeadstart,
+            (Opponent::Human, Message::HeadstartComputer) => Message::TwoPlayerHeadstartOpponent,
+            (Opponent::Human, Message::BoosterSelection) => Message::TwoPlayerBoosterSelection,
+            (Opponent::Human, Message::MoveSelectionAndOutcome) => Message::TwoPlayerMoveSelectionAndOutcome,
+            (Opponent::Human, Message::PointComputer) => Message::TwoPlayerPointOpponent,
+            (Opponent::Human, Message::ScoreUpdate) => Message::TwoPlayerScoreUpdate,
+            (Opponent::Human, Message::GameOver) => Message::TwoPlayerGameOver,
+            (Opponent::Human, Message::ComputerWins) => Message::TwoPlayerOpponentWins,
+            (Opponent::Human, Message::NotEnoughWaits) => Message::TwoPlayerNotEnoughWaits,
+            (Opponent::Human, message) => message,
+        }
+    }
+}
+
+/// A language the game can be played in.
+pub trait Locale {
+    fn character_name(&self, character: Character) -> String;
+    fn booster_name(&self, booster: Booster) -> String;
+    fn move_name(&self, selected_move: Move) -> String;
+
+    /// Other names users can type to choose `character`.
+    fn character_aliases(&self, _character: Character) -> Vec<String> {
+        vec![]
+    }
+
+    /// Other names users can type to choose `booster`.
+    fn booster_aliases(&self, _booster: Booster) -> Vec<String> {
+        vec![]
+    }
+
+    /// Other names users can type to choose `selected_move`.
+    fn move_aliases(&self, _selected_move: Move) -> Vec<String> {
+        vec![]
+    }
+
+    /// The template for `message`.
+    fn message(&self, message: Message) -> String;
+
+    /// Finds the character named (or aliased) `text`, ignoring case and whitespace.
+    fn parse_character(&self, text: &str) -> Option<Character> {
+        find(text, &CHARACTERS, |&c| self.character_name(c), |&c| self.character_aliases(c))
+    }
+
+    /// Finds the booster named (or aliased) `text`, ignoring case and whitespace.
+    fn parse_booster(&self, text: &str) -> Option<Booster> {
+        find(text, &BOOSTERS, |&b| self.booster_name(b), |&b| self.booster_aliases(b))
+    }
+
+    /// Finds the move named (or aliased) `text`, ignoring case and whitespace.
+    fn parse_move(&self, text: &str) -> Option<Move> {
+        find(text, &MOVES, |&m| self.move_name(m), |&m| self.move_aliases(m))
+    }
+
+    /// Like `Question::parse_answer`, but in this locale.
+    fn parse_answer(&self, question: &Question, text: &str) -> Answer {
+        let text = text.trim();
+
+        match *question {
+            Question::ChooseCharacter { .. } => Answer::CharacterSelection(
+                self.parse_character(text)
+                    .map(CharacterSelection::Character)
+                    .unwrap_or_else(|| CharacterSelection::Nonexistent(text.to_string()))
+            ),
+            Question::ChooseBooster { .. } => Answer::BoosterSelection(
+                self.parse_booster(text)
+                    .map(BoosterSelection::Booster)
+                    .unwrap_or_else(|| BoosterSelection::Nonexistent(text.to_string()))
+            ),
+            Question::ChooseMove { .. } => Answer::MoveSelection(
+                self.parse_move(text)
+                    .map(MoveSelection::Move)
+                    .unwrap_or_else(|| MoveSelection::Nonexistent(text.to_string()))
+            ),
+        }
+    }
+
+    fn render_question(&self, question: &Question) -> String {
+        let separator = self.message(Message::ListSeparator);
+
+        let (message, options) = match *question {
+            Question::ChooseCharacter { ref available_characters } => (
+                Message::ChooseCharacter,
+                available_characters.iter().map(|&c| self.character_name(c)).collect::<Vec<_>>(),
+            ),
+            Question::ChooseBooster { ref available_boosters } => (
+                Message::ChooseBooster,
+                available_boosters.iter().map(|&b| self.booster_name(b)).collect(),
+            ),
+            Question::ChooseMove { ref available_moves } => (
+                Message::ChooseMove,
+                available_moves.iter().map(|&m| self.move_name(m)).collect(),
+            ),
+        };
+
+        fill(&self.message(message), &[("options", &options.join(&separator))])
+    }
+
+    /// Describes `notification` from the human's point of view, in a single-player game.
+    ///
+    /// Penalties are explained using the streak limits and wait costs of `rules`.
+    fn render_notification(&self, notification: &Notification, rules: &Rules) -> String {
+        self.render_notification_against(notification, rules, Opponent::Computer)
+    }
+
+    /// Like `render_notification`, but worded for a game against `opponent`.
+    fn render_notification_against(&self, notification: &Notification, rules: &Rules, opponent: Opponent) -> String {
+        let message = |message: Message| self.message(message.against(opponent));
+
+        if let Some((explanation, waits)) = penalty_explanation(self, notification, rules) {
+            return fill(&message(Message::Penalty), &[
+                ("explanation", &explanation),
+                ("waits", &waits_text(self, waits)),
+            ]);
+        }
+
+        match *notification {
+            Notification::CharacterSelectionAndHeadstart { human_character, computer_character, who_gets_the_headstart } => {
+                let headstart = match who_gets_the_headstart {
+                    WhoGetsTheHeadstart::Neither => Message::HeadstartNeither,
+                    WhoGetsTheHeadstart::JustHuman => Message::HeadstartHuman,
+                    WhoGetsTheHeadstart::JustComputer => Message::HeadstartComputer,
+                };
+                fill(&message(Message::CharacterSelectionAndHeadstart), &[
+                    ("human", &self.character_name(human_character)),
+                    ("computer", &self.character_name(computer_character)),
+                    ("headstart", &message(headstart)),
+                ])
+            },
+            Notification::SameCharacterSelection { both_character } => {
+                fill(&message(Message::SameCharacterSelection), &[
+                    ("character", &self.character_name(both_character)),
+                ])
+            },
+            Notification::BoosterSelection { human_booster, computer_booster } => {
+                fill(&message(Message::BoosterSelection), &[
+                    ("human", &self.booster_name(human_booster)),
+                    ("computer", &self.booster_name(computer_booster)),
+                ])
+            },
+            Notification::MoveSelectionAndOutcome { human_move, computer_move, who_gets_the_point } => {
+                let point = match who_gets_the_point {
+                    WhoGetsThePoint::Neither => Message::PointNeither,
+                    WhoGetsThePoint::JustHuman => Message::PointHuman,
+                    WhoGetsThePoint::JustComputer => Message::PointComputer,
+                    WhoGetsThePoint::Both => Message::PointBoth,
+                };
+                fill(&message(Message::MoveSelectionAndOutcome), &[
+                    ("human", &self.move_name(human_move)),
+                    ("computer", &self.move_name(computer_move)),
+                    ("point", &message(point)),
+                ])
+            },
+            Notification::ScoreUpdate { human_points, computer_points } => {
+                fill(&message(Message::ScoreUpdate), &[
+                    ("human", &human_points.to_string()),
+                    ("computer", &computer_points.to_string()),
+                ])
+            },
+            Notification::TiebreakingScoreSetback { both_points } => {
+                fill(&message(Message::TiebreakingScoreSetback), &[
+                    ("points", &both_points.to_string()),
+                    ("tiebreak_points", &rules.tiebreak_points.to_string()),
+                ])
+            },
+            Notification::GameOver { human_points, computer_points } => {
+                let winner = if human_points > computer_points {
+                    Message::HumanWins
+                } else {
+                    Message::ComputerWins
+                };
+                fill(&message(Message::GameOver), &[
+                    ("winner", &message(winner)),
+                    ("human", &human_points.to_string()),
+                    ("computer", &computer_points.to_string()),
+                ])
+            },
+            Notification::NotEnoughWaits => message(Message::NotEnoughWaits),
+            Notification::OpponentPenalty { ref penalty } => {
+                if let Notification::NotEnoughWaits = **penalty {
+                    return message(Message::OpponentNotEnoughWaits);
+                }
+
+                let (explanation, waits) = penalty_explanation(self, penalty, rules)
+                    .unwrap_or_else(|| (String::new(), 0));
+                fill(&message(Message::OpponentPenalty), &[
+                    ("explanation", &explanation),
+                    ("waits", &waits_text(self, waits)),
+                ])
+            },
+            _ => unreachable!("Penalties are rendered above"),
+        }
+    }
+}
+
+/// Replaces each `{key}` in `template` with its value, leaving unknown placeholders as they are.
+///
+/// Placeholders are replaced in a single pass, so values (e.g., names users typed) are never filled in themselves.
+pub fn fill(template: &str, values: &[(&str, &str)]) -> String {
+    let mut filled = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        filled.push_str(&rest[..start]);
+        let after_brace = &rest[start + 1..];
+        let placeholder = after_brace.find('}').and_then(|end| {
+            values.iter()
+                .find(|&&(key, _)| key == &after_brace[..end])
+                .map(|&(_, value)| (value, end))
+        });
+
+        match placeholder {
+            Some((value, end)) => {
+                filled.push_str(value);
+                rest = &after_brace[end + 1..];
+            },
+            None => {
+                filled.push('{');
+                rest = after_brace;
+            },
+        }
+    }
+    filled.push_str(rest);
+
+    filled
+}
+
+fn find<T, N, A>(text: &str, options: &[T], name: N, aliases: A) -> Option<T>
+    where T: Copy,
+          N: Fn(&T) -> String,
+          A: Fn(&T) -> Vec<String>
+{
+    let text = helpers::lowercase_no_whitespace(text);
+
+    options.iter()
+        .find(|option| {
+            helpers::lowercase_no_whitespace(&name(option)) == text
+                || aliases(option).iter().any(|alias| helpers::lowercase_no_whitespace(alias) == text)
+        })
+        .cloned()
+}
+
+/// "1 wait" or "{waits} waits" in `locale`.
+pub fn waits_text<L: Locale + ?Sized>(locale: &L, waits: u8) -> String {
+    if waits == 1 {
+        locale.message(Message::OneWait)
+    } else {
+        fill(&locale.message(Message::ManyWaits), &[("waits", &waits.to_string())])
+    }
+}
+
+/// Why a penalty was given (without saying who got it), and how many waits it cost.
+///
+/// Returns `None` if `notification` isn't a penalty.
+fn penalty_explanation<L: Locale + ?Sized>(locale: &L, notification: &Notification, rules: &Rules) -> Option<(String, u8)> {
+    let (message, name, limit, waits) = match *notification {
+        Notification::CharacterNonexistentPenalty { ref attempted_character_name } => {
+            (Message::NonexistentCharacter, attempted_character_name.clone(), 0, rules.nonexistent_penalty)
+        },
+        Notification::CharacterThreeTimesInARowPenalty { attempted_character } => {
+            (Message::TooManyTimesInARow, locale.character_name(attempted_character), rules.character_streak_limit, rules.three_times_in_a_row_penalty)
+        },
+        Notification::BoosterNonexistentPenalty { ref attempted_booster_name } => {
+            (Message::NonexistentBooster, attempted_booster_name.clone(), 0, rules.nonexistent_penalty)
+        },
+        Notification::BoosterFromWrongCharacterPenalty { attempted_booster } => {
+            (Message::FromWrongCharacter, locale.booster_name(attempted_booster), 0, rules.wrong_character_penalty)
+        },
+        Notification::MoveNonexistentPenalty { ref attempted_move_name } => {
+            (Message::NonexistentMove, attempted_move_name.clone(), 0, rules.nonexistent_penalty)
+        },
+        Notification::MoveThreeTimesInARowPenalty { attempted_move } => {
+            (Message::TooManyTimesInARow, locale.move_name(attempted_move), rules.move_streak_limit, rules.three_times_in_a_row_penalty)
+        },
+        Notification::MoveSingleUsePenalty { attempted_move } => {
+            (Message::SingleUse, locale.move_name(attempted_move), 0, rules.destroyed_move_penalty)
+        },
+        Notification::MoveDestroyedPenalty { attempted_move } => {
+            (Message::Destroyed, locale.move_name(attempted_move), 0, rules.destroyed_move_penalty)
+        },
+        Notification::MoveFromWrongCharacterPenalty { attempted_move } => {
+            (Message::FromWrongCharacter, locale.move_name(attempted_move), 0, rules.wrong_character_penalty)
+        },
+        Notification::MoveFromWrongBoosterPenalty { attempted_move } => {
+            (Message::FromWrongBooster, locale.move_name(attempted_move), 0, rules.wrong_booster_penalty)
+        },
+        _ => return None,
+    };
+
+    let explanation = fill(&locale.message(message), &[
+        ("name", &name),
+        ("limit", &limit.to_string()),
+    ]);
+
+    Some((explanation, waits))
+}
+
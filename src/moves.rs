@@ -2,7 +2,10 @@ use std::str::FromStr;
 use std::fmt;
 use super::helpers;
 
-#[derive(Clone, Copy, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "python", pyo3::pyclass(module = "nzsc_single_player", eq, eq_int, hash, frozen, from_py_object))]
+#[repr(u8)]
 pub enum Move {
     Kick = 0,
     NinjaSword = 1,
@@ -38,6 +41,11 @@ impl Move {
     pub fn to_u8(self) -> u8 {
         self as u8
     }
+
+    /// The inverse of `to_u8`.
+    pub fn from_u8(id: u8) -> Option<Move> {
+        MOVES.get(id as usize).cloned()
+    }
 }
 
 impl FromStr for Move {
@@ -80,41 +88,73 @@ impl FromStr for Move {
 
 impl fmt::Display for Move {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let string = match self {
-            &Move::Kick => "Kick",
-            &Move::NinjaSword => "Ninja Sword",
-            &Move::Nunchucks => "Nunchucks",
-            &Move::ShadowFireball => "Shadow Fireball",
-            &Move::ShadowSlip => "Shadow Slip",
-            &Move::RunInCircles => "Run In Circles",
-            &Move::LightningFastKarateChop => "Lightning Fast Karate Chop",
-            &Move::Rampage => "Rampage",
-            &Move::Muscle => "Muscle",
-            &Move::Zap => "Zap",
-            &Move::Regenerate => "Regenerate",
-            &Move::Gravedigger => "Gravedigger",
-            &Move::ZombieCorps => "Zombie Corps",
-            &Move::Apocalypse => "Apocalypse",
-            &Move::SamuraiSword => "Samurai Sword",
-            &Move::Helmet => "Helmet",
-            &Move::Smash => "Smash",
-            &Move::Lightning => "Lightning",
-            &Move::Earthquake => "Earthquake",
-            &Move::Twist => "Twist",
-            &Move::Bend => "Bend",
-            &Move::JugglingKnives => "Juggling Knives",
-            &Move::AcidSpray => "Acid Spray",
-            &Move::Nose => "Nose",
-            &Move::BackwardsMoustachio => "Backwards Moustachio",
-            &Move::NoseOfTheTaunted => "Nose Of The Taunted",
-            &Move::MustacheMash => "Mustache Mash",
-            &Move::BigHairyDeal => "Big Hairy Deal"
+        let string = match *self {
+            Move::Kick => "Kick",
+            Move::NinjaSword => "Ninja Sword",
+            Move::Nunchucks => "Nunchucks",
+            Move::ShadowFireball => "Shadow Fireball",
+            Move::ShadowSlip => "Shadow Slip",
+            Move::RunInCircles => "Run In Circles",
+            Move::LightningFastKarateChop => "Lightning Fast Karate Chop",
+            Move::Rampage => "Rampage",
+            Move::Muscle => "Muscle",
+            Move::Zap => "Zap",
+            Move::Regenerate => "Regenerate",
+            Move::Gravedigger => "Gravedigger",
+            Move::ZombieCorps => "Zombie Corps",
+            Move::Apocalypse => "Apocalypse",
+            Move::SamuraiSword => "Samurai Sword",
+            Move::Helmet => "Helmet",
+            Move::Smash => "Smash",
+            Move::Lightning => "Lightning",
+            Move::Earthquake => "Earthquake",
+            Move::Twist => "Twist",
+            Move::Bend => "Bend",
+            Move::JugglingKnives => "Juggling Knives",
+            Move::AcidSpray => "Acid Spray",
+            Move::Nose => "Nose",
+            Move::BackwardsMoustachio => "Backwards Moustachio",
+            Move::NoseOfTheTaunted => "Nose Of The Taunted",
+            Move::MustacheMash => "Mustache Mash",
+            Move::BigHairyDeal => "Big Hairy Deal"
         };
 
         write!(f, "{}", string)
     }
 }
 
+/// Every move, in order.
+pub const MOVES: [Move; 28] = [
+    Move::Kick,
+    Move::NinjaSword,
+    Move::Nunchucks,
+    Move::ShadowFireball,
+    Move::ShadowSlip,
+    Move::RunInCircles,
+    Move::LightningFastKarateChop,
+    Move::Rampage,
+    Move::Muscle,
+    Move::Zap,
+    Move::Regenerate,
+    Move::Gravedigger,
+    Move::ZombieCorps,
+    Move::Apocalypse,
+    Move::SamuraiSword,
+    Move::Helmet,
+    Move::Smash,
+    Move::Lightning,
+    Move::Earthquake,
+    Move::Twist,
+    Move::Bend,
+    Move::JugglingKnives,
+    Move::AcidSpray,
+    Move::Nose,
+    Move::BackwardsMoustachio,
+    Move::NoseOfTheTaunted,
+    Move::MustacheMash,
+    Move::BigHairyDeal
+];
+
 pub const SINGLE_USE_MOVES: [Move; 3] = [
     Move::Zap,
     Move::Regenerate,
@@ -0,0 +1,196 @@
+use super::players::{
+    CharacterlessPlayer,
+    BoosterlessPlayer,
+    Player,
+};
+use super::characters::Character;
+use super::boosters::Booster;
+use super::moves::Move;
+use super::outcomes;
+use super::rules::Rules;
+
+const EPSILON: f64 = 1e-9;
+
+/// The solution to a simultaneous choice between two players.
+///
+/// Each strategy pairs every available choice with the probability it should be chosen.
+/// `value` is the expected point differential (`player`'s points minus `opponent`'s points)
+/// when both players follow their strategies.
+#[derive(Clone, Debug)]
+pub struct Equilibrium<T> {
+    pub player_strategy: Vec<(T, f64)>,
+    pub opponent_strategy: Vec<(T, f64)>,
+    pub value: f64,
+}
+
+/// Solves the next move round between `player` and `opponent` under `rules`.
+pub fn solve_moves(player: &Player, opponent: &Player, rules: &Rules) -> Equilibrium<Move> {
+    let player_moves = player.available_moves_with_rules(rules);
+    let opponent_moves = opponent.available_moves_with_rules(rules);
+    let payoffs: Vec<Vec<f64>> = player_moves.iter()
+        .map(|&player_move| {
+            opponent_moves.iter()
+                .map(|&opponent_move| {
+                    let points = outcomes::get_move_points(player_move, player.booster, opponent_move, opponent.booster);
+
+                    points.0 as f64 - points.1 as f64
+                })
+                .collect()
+        })
+        .collect();
+
+    solve(player_moves, opponent_moves, &payoffs)
+}
+
+/// Solves the booster choice between `player` and `opponent` under `rules`.
+///
+/// Each pair of boosters is worth the value of the first move round it leads to.
+/// Since the computer never chooses `Booster::None`, it's left out for both players.
+pub fn solve_boosters(player: &BoosterlessPlayer, opponent: &BoosterlessPlayer, rules: &Rules) -> Equilibrium<Booster> {
+    let without_none = |boosters: Vec<Booster>| -> Vec<Booster> {
+        boosters.into_iter()
+            .filter(|&booster| booster != Booster::None)
+            .collect()
+    };
+    let player_boosters = without_none(player.available_boosters());
+    let opponent_boosters = without_none(opponent.available_boosters());
+    let payoffs: Vec<Vec<f64>> = player_boosters.iter()
+        .map(|&player_booster| {
+            opponent_boosters.iter()
+                .map(|&opponent_booster| {
+                    solve_moves(&player.to_player(player_booster), &opponent.to_player(opponent_booster), rules).value
+                })
+                .collect()
+        })
+        .collect();
+
+    solve(player_boosters, opponent_boosters, &payoffs)
+}
+
+/// Solves the character choice between `player` and `opponent` under `rules`.
+///
+/// Each pair of characters is worth the headstart differential it leads to.
+pub fn solve_characters(player: &CharacterlessPlayer, opponent: &CharacterlessPlayer, rules: &Rules) -> Equilibrium<Character> {
+    let player_characters = player.available_characters_with_rules(rules);
+    let opponent_characters = opponent.available_characters_with_rules(rules);
+    let payoffs: Vec<Vec<f64>> = player_characters.iter()
+        .map(|&player_character| {
+            opponent_characters.iter()
+                .map(|&opponent_character| {
+                    let headstart = outcomes::get_headstart(player_character, opponent_character);
+
+                    headstart.0 as f64 - headstart.1 as f64
+                })
+                .collect()
+        })
+        .collect();
+
+    solve(player_characters, opponent_characters, &payoffs)
+}
+
+/// Solves a zero-sum matrix game where `payoffs[i][j]` is what the player gets
+/// when they choose `player_choices[i]` and the opponent chooses `opponent_choices[j]`.
+///
+/// If either player has no choices, both strategies are empty and the value is 0.
+pub fn solve<T>(player_choices: Vec<T>, opponent_choices: Vec<T>, payoffs: &[Vec<f64>]) -> Equilibrium<T> {
+    if player_choices.is_empty() || opponent_choices.is_empty() {
+        return Equilibrium {
+            player_strategy: vec![],
+            opponent_strategy: vec![],
+            value: 0.0,
+        };
+    }
+
+    let (player_probabilities, opponent_probabilities, value) = solve_matrix(payoffs);
+
+    Equilibrium {
+        player_strategy: player_choices.into_iter().zip(player_probabilities).collect(),
+        opponent_strategy: opponent_choices.into_iter().zip(opponent_probabilities).collect(),
+        value,
+    }
+}
+
+/// Solves a (non-empty) zero-sum matrix game with the simplex method.
+///
+/// The payoffs are shifted to be positive, so the opponent's problem becomes
+/// maximizing `sum(y)` subject to `A y <= 1` and `y >= 0`.
+/// The player's strategy is read from the dual values in the final tableau.
+/// Rounding errors are clamped so no probability is negative.
+/// Returns the player's probabilities, the opponent's probabilities, and the game value.
+fn solve_matrix(payoffs: &[Vec<f64>]) -> (Vec<f64>, Vec<f64>, f64) {
+    let rows = payoffs.len();
+    let columns = payoffs[0].len();
+    let min_payoff = payoffs.iter()
+        .flat_map(|row| row.iter().cloned())
+        .fold(f64::INFINITY, f64::min);
+    let shift = 1.0 - min_payoff;
+
+    // Each tableau row is `[A_i | I_i | 1]`, and the last row is the objective.
+    let width = columns + rows + 1;
+    let mut tableau = vec![vec![0.0; width]; rows + 1];
+    for (i, row) in payoffs.iter().enumerate() {
+        for (j, &payoff) in row.iter().enumerate() {
+            tableau[i][j] = payoff + shift;
+        }
+        tableau[i][columns + i] = 1.0;
+        tableau[i][width - 1] = 1.0;
+    }
+    for value in tableau[rows].iter_mut().take(columns) {
+        *value = -1.0;
+    }
+    let mut basis: Vec<usize> = (columns..columns + rows).collect();
+
+    // Bland's rule prevents cycling.
+    while let Some(entering) = (0..width - 1).find(|&j| tableau[rows][j] < -EPSILON) {
+        let leaving = (0..rows)
+            .filter(|&i| tableau[i][entering] > EPSILON)
+            .fold(None, |best: Option<usize>, i| {
+                match best {
+                    None => Some(i),
+                    Some(best) => {
+                        let ratio = tableau[i][width - 1] / tableau[i][entering];
+                        let best_ratio = tableau[best][width - 1] / tableau[best][entering];
+
+                        if ratio < best_ratio - EPSILON
+                            || (ratio < best_ratio + EPSILON && basis[i] < basis[best])
+                        {
+                            Some(i)
+                        } else {
+                            Some(best)
+                        }
+                    },
+                }
+            })
+            .expect("Matrix game LP is bounded");
+
+        let pivot = tableau[leaving][entering];
+        for value in tableau[leaving].iter_mut() {
+            *value /= pivot;
+        }
+        let pivot_row = tableau[leaving].clone();
+        for (i, row) in tableau.iter_mut().enumerate() {
+            let factor = row[entering];
+            if i != leaving && factor != 0.0 {
+                for (value, &pivot_value) in row.iter_mut().zip(&pivot_row) {
+                    *value -= factor * pivot_value;
+                }
+            }
+        }
+        basis[leaving] = entering;
+    }
+
+    let total = tableau[rows][width - 1];
+    let shifted_value = 1.0 / total;
+
+    let mut opponent_probabilities = vec![0.0; columns];
+    for (i, &variable) in basis.iter().enumerate() {
+        if variable < columns {
+            opponent_probabilities[variable] = (tableau[i][width - 1] * shifted_value).max(0.0);
+        }
+    }
+    let player_probabilities = (0..rows)
+        .map(|i| (tableau[rows][columns + i] * shifted_value).max(0.0))
+        .collect();
+
+    (player_probabilities, opponent_probabilities, shifted_value - shift)
+}
@@ -1,5 +1,6 @@
 use super::moves::Move;
 use super::characters::Character;
+use super::boosters::Booster;
 
 const MOVE_OUTCOMES: [u8; 28 * 28] = [
     0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 1, 1, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 0, 0, 0, 1,
@@ -58,6 +59,30 @@ pub fn get_points(moves: Vec<Move>) -> Vec<u8> {
     points
 }
 
+/// Gets the points `a` and `b` get when `a` chooses `a_move` and `b` chooses `b_move`.
+///
+/// Unlike `get_points`, this accounts for boosters:
+/// Shadow Fireball beats Smash, unless the Smash player has the Strong booster.
+pub fn get_move_points(a_move: Move, a_booster: Booster, b_move: Move, b_booster: Booster) -> (u8, u8) {
+    if a_move == Move::ShadowFireball && b_move == Move::Smash {
+        if b_booster == Booster::Strong {
+            (0, 1)
+        } else {
+            (1, 0)
+        }
+    } else if a_move == Move::Smash && b_move == Move::ShadowFireball {
+        if a_booster == Booster::Strong {
+            (1, 0)
+        } else {
+            (0, 1)
+        }
+    } else {
+        let points = get_points(vec![a_move, b_move]);
+
+        (points[0], points[1])
+    }
+}
+
 pub fn get_headstart(a: Character, b: Character) -> Headstart {
     let a = a.to_u8();
     let b = b.to_u8();
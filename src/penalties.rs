@@ -0,0 +1,124 @@
+use super::players::{
+    CharacterlessPlayer,
+    BoosterlessPlayer,
+    Player,
+};
+use super::io;
+use super::rules::Rules;
+
+use super::characters::Character;
+use super::boosters::Booster;
+use super::moves::{
+    Move,
+    SINGLE_USE_MOVES,
+};
+
+/// What a player loses for an illegal selection.
+pub struct Penalty {
+    pub waits: u8,
+    pub notification: io::Notification,
+}
+
+impl Penalty {
+    fn new(waits: u8, notification: io::Notification) -> Penalty {
+        Penalty {
+            waits,
+            notification,
+        }
+    }
+}
+
+/// The notifications for a penalty, given the points the penalized player's opponent got for it
+/// (i.e., what `penalize_waits` returned).
+pub fn notifications(notification: io::Notification, opponent_points: u8) -> Vec<io::Notification> {
+    if opponent_points == 0 {
+        vec![notification]
+    } else {
+        vec![notification, io::Notification::NotEnoughWaits]
+    }
+}
+
+/// Returns the selected character if `player` may choose it under `rules`, or the penalty for choosing it otherwise.
+pub fn validate_character(player: &CharacterlessPlayer, rules: &Rules, selection: io::CharacterSelection) -> Result<Character, Penalty> {
+    match selection {
+        io::CharacterSelection::Character(character) => {
+            if player.available_characters_with_rules(rules).contains(&character) {
+                Ok(character)
+            } else {
+                Err(Penalty::new(rules.three_times_in_a_row_penalty, io::Notification::CharacterThreeTimesInARowPenalty {
+                    attempted_character: character,
+                }))
+            }
+        },
+        io::CharacterSelection::Nonexistent(attempted_character_name) => {
+            Err(Penalty::new(rules.nonexistent_penalty, io::Notification::CharacterNonexistentPenalty {
+                attempted_character_name,
+            }))
+        },
+    }
+}
+
+/// Returns the selected booster if `player` may choose it under `rules`, or the penalty for choosing it otherwise.
+pub fn validate_booster(player: &BoosterlessPlayer, rules: &Rules, selection: io::BoosterSelection) -> Result<Booster, Penalty> {
+    match selection {
+        io::BoosterSelection::Booster(booster) => {
+            if player.available_boosters().contains(&booster) {
+                Ok(booster)
+            } else {
+                Err(Penalty::new(rules.wrong_character_penalty, io::Notification::BoosterFromWrongCharacterPenalty {
+                    attempted_booster: booster,
+                }))
+            }
+        },
+        io::BoosterSelection::Nonexistent(attempted_booster_name) => {
+            Err(Penalty::new(rules.nonexistent_penalty, io::Notification::BoosterNonexistentPenalty {
+                attempted_booster_name,
+            }))
+        },
+    }
+}
+
+/// Returns the selected move if `player` may choose it under `rules`, or the penalty for choosing it otherwise.
+pub fn validate_move(player: &Player, rules: &Rules, selection: io::MoveSelection) -> Result<Move, Penalty> {
+    match selection {
+        io::MoveSelection::Move(attempted_move) => {
+            if player.available_moves_with_rules(rules).contains(&attempted_move) {
+                Ok(attempted_move)
+            } else if player.destroyed_moves.contains(&attempted_move) {
+                if SINGLE_USE_MOVES.contains(&attempted_move) {
+                    Err(Penalty::new(rules.destroyed_move_penalty, io::Notification::MoveSingleUsePenalty {
+                        attempted_move,
+                    }))
+                } else {
+                    Err(Penalty::new(rules.destroyed_move_penalty, io::Notification::MoveDestroyedPenalty {
+                        attempted_move,
+                    }))
+                }
+            } else if player.move_streak.times >= rules.move_streak_limit && player.move_streak.repeated_move == Some(attempted_move) {
+                Err(Penalty::new(rules.three_times_in_a_row_penalty, io::Notification::MoveThreeTimesInARowPenalty {
+                    attempted_move,
+                }))
+            } else {
+                let mut booster_moves = vec![];
+                for booster in &player.character.get_boosters() {
+                    booster_moves.extend(booster.get_moves());
+                }
+
+                if booster_moves.contains(&attempted_move) {
+                    Err(Penalty::new(rules.wrong_booster_penalty, io::Notification::MoveFromWrongBoosterPenalty {
+                        attempted_move,
+                    }))
+                } else {
+                    Err(Penalty::new(rules.wrong_character_penalty, io::Notification::MoveFromWrongCharacterPenalty {
+                        attempted_move,
+                    }))
+                }
+            }
+        },
+        io::MoveSelection::Nonexistent(attempted_move_name) => {
+            Err(Penalty::new(rules.nonexistent_penalty, io::Notification::MoveNonexistentPenalty {
+                attempted_move_name,
+            }))
+        },
+    }
+}
@@ -1,12 +1,19 @@
-use super::moves::Move;
+use super::moves::{
+    Move,
+    SINGLE_USE_MOVES,
+    DESTRUCTIVE_MOVES,
+};
 use super::boosters::Booster;
+use super::outcomes;
 use super::characters::Character;
+use super::rules::Rules;
 use super::streaks::{
     MoveStreak,
     CharacterStreak
 };
 
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CharacterlessPlayer {
     pub points: u8,
     pub waits: u8,
@@ -14,6 +21,7 @@ pub struct CharacterlessPlayer {
 }
 
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BoosterlessPlayer {
     pub points: u8,
     pub waits: u8,
@@ -21,6 +29,7 @@ pub struct BoosterlessPlayer {
 }
 
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Player {
     pub points: u8,
     pub waits: u8,
@@ -31,15 +40,29 @@ pub struct Player {
 }
 
 impl CharacterlessPlayer {
+    /// Creates a player for a game with the standard rules.
     pub fn new() -> CharacterlessPlayer {
+        CharacterlessPlayer::with_rules(Rules::standard())
+    }
+
+    /// Creates a player for a game with `rules`.
+    ///
+    /// The rules aren't stored in the player, so pass the same rules
+    /// to methods like `available_characters_with_rules`.
+    pub fn with_rules(rules: Rules) -> CharacterlessPlayer {
         CharacterlessPlayer {
             points: 0,
-            waits: 4,
+            waits: rules.starting_waits,
             character_streak: CharacterStreak::new(),
         }
     }
 
+    /// The characters available under the standard rules.
     pub fn available_characters(&self) -> Vec<Character> {
+        self.available_characters_with_rules(&Rules::standard())
+    }
+
+    pub fn available_characters_with_rules(&self, rules: &Rules) -> Vec<Character> {
         let mut characters = vec![
             Character::Ninja,
             Character::Zombie,
@@ -47,7 +70,7 @@ impl CharacterlessPlayer {
             Character::Clown,
         ];
 
-        if self.character_streak.times == 3 {
+        if self.character_streak.times >= rules.character_streak_limit {
             characters.retain(|&c| Some(c) != self.character_streak.repeated_character);
         }
 
@@ -57,10 +80,10 @@ impl CharacterlessPlayer {
     pub fn penalize_waits(&mut self, waits: u8) -> u8 {
         if self.waits < waits {
             self.waits = 0;
-            return 1;
+            1
         } else {
             self.waits -= waits;
-            return 0;
+            0
         }
     }
 
@@ -73,6 +96,12 @@ impl CharacterlessPlayer {
     }
 }
 
+impl Default for CharacterlessPlayer {
+    fn default() -> CharacterlessPlayer {
+        CharacterlessPlayer::new()
+    }
+}
+
 impl BoosterlessPlayer {
     pub fn available_boosters(&self) -> Vec<Booster> {
         self.character.get_boosters()
@@ -81,10 +110,10 @@ impl BoosterlessPlayer {
     pub fn penalize_waits(&mut self, waits: u8) -> u8 {
         if self.waits < waits {
             self.waits = 0;
-            return 1;
+            1
         } else {
             self.waits -= waits;
-            return 0;
+            0
         }
     }
 
@@ -101,7 +130,12 @@ impl BoosterlessPlayer {
 }
 
 impl Player {
+    /// The moves available under the standard rules.
     pub fn available_moves(&self) -> Vec<Move> {
+        self.available_moves_with_rules(&Rules::standard())
+    }
+
+    pub fn available_moves_with_rules(&self, rules: &Rules) -> Vec<Move> {
         let character_moves = self.character.get_moves();
         let booster_moves = self.booster.get_moves();
 
@@ -113,7 +147,7 @@ impl Player {
         available_moves.retain(|&a| !destroyed_moves.contains(&a));
 
         if let Some(streak_move) = self.move_streak.repeated_move {
-            if self.move_streak.times >= 3 {
+            if self.move_streak.times >= rules.move_streak_limit {
                 available_moves.retain(|&a| a != streak_move);
             }
         }
@@ -124,10 +158,37 @@ impl Player {
     pub fn penalize_waits(&mut self, waits: u8) -> u8 {
         if self.waits < waits {
             self.waits = 0;
-            return 1;
+            1
         } else {
             self.waits -= waits;
-            return 0;
+            0
         }
     }
 }
+
+/// Plays a round where `a` chooses `a_move` and `b` chooses `b_move`.
+///
+/// Updates both players' move streaks, destroyed moves, and points.
+/// Returns the points `a` and `b` got (in that order).
+pub fn play_moves(a: &mut Player, a_move: Move, b: &mut Player, b_move: Move) -> (u8, u8) {
+    a.move_streak.add(a_move);
+    b.move_streak.add(b_move);
+
+    if SINGLE_USE_MOVES.contains(&a_move)
+        || DESTRUCTIVE_MOVES.contains(&b_move)
+    {
+        a.destroyed_moves.push(a_move);
+    }
+    if SINGLE_USE_MOVES.contains(&b_move)
+        || DESTRUCTIVE_MOVES.contains(&a_move)
+    {
+        b.destroyed_moves.push(b_move);
+    }
+
+    let points = outcomes::get_move_points(a_move, a.booster, b_move, b.booster);
+
+    a.points += points.0;
+    b.points += points.1;
+
+    points
+}
@@ -1,3 +1,10 @@
+use std::time::{
+    SystemTime,
+    UNIX_EPOCH,
+};
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PseudorandomFloatGenerator {
     state: u32,
 }
@@ -12,6 +19,7 @@ impl PseudorandomFloatGenerator {
         }
     }
 
+    #[allow(clippy::should_implement_trait)]
     pub fn next(&mut self) -> f64 {
         // Calculate random u32.
         // https://en.wikipedia.org/wiki/Xorshift
@@ -26,4 +34,19 @@ impl PseudorandomFloatGenerator {
         // Convert to u16 and divide by (2 ** 16) to get random float
         (x >> 16) as f64 / 65536.0
     }
+
+    /// A seed from the current time, for games that don't need to be reproducible.
+    ///
+    /// The generator gets stuck at zero, so this is never zero.
+    pub fn clock_seed() -> u32 {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).expect("The clock is before 1970");
+
+        ((now.as_secs() as u32) ^ now.subsec_nanos()) | 1
+    }
+
+    pub fn generate_random_index_from_inclusive_max(&mut self, inclusive_max: usize) -> usize {
+        let inclusive_max = inclusive_max as f64;
+
+        (self.next() * (inclusive_max + 1.0)).floor() as usize
+    }
 }
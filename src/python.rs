@@ -0,0 +1,522 @@
+//! A Python module (`nzsc_single_player`) for scripting games and researching bots.
+//!
+//! Build it with `maturin develop --features python,pyo3/extension-module`.
+//! `Question` and `Notification` are Python classes with a subclass for each variant
+//! (e.g., `Notification.MoveSelectionAndOutcome`), so they work with `isinstance` and `match`.
+//!
+//! `tests/python/test_smoke.py` is a pytest smoke test of the module, which `tests/python.rs` also runs.
+
+use pyo3::prelude::*;
+use pyo3::exceptions::PyValueError;
+use pyo3::types::PyList;
+
+use super::single_player_game::SinglePlayerNZSCGame;
+use super::strategies::{
+    ComputerStrategy,
+    RandomStrategy,
+    HardStrategy,
+    NashStrategy,
+    AdaptiveStrategy,
+};
+use super::history::{
+    HistoryEntry,
+    ComputerChoice,
+};
+use super::rules::Rules;
+use super::characters::{
+    Character,
+    CHARACTERS,
+};
+use super::boosters::{
+    Booster,
+    BOOSTERS,
+};
+use super::moves::{
+    Move,
+    MOVES,
+};
+use super::outcomes;
+use super::io::{
+    self,
+    WhoGetsThePoint,
+    WhoGetsTheHeadstart,
+};
+
+#[pymethods]
+impl Character {
+    /// Parses a character name, like `Character(str)` would if it were a Python enum.
+    #[staticmethod]
+    fn parse(name: &str) -> PyResult<Character> {
+        name.parse().map_err(|_| PyValueError::new_err(format!("There's no character called \"{}\".", name)))
+    }
+
+    #[staticmethod]
+    fn all() -> Vec<Character> {
+        CHARACTERS.to_vec()
+    }
+
+    #[pyo3(name = "get_moves")]
+    fn py_get_moves(&self) -> Vec<Move> {
+        self.get_moves()
+    }
+
+    #[pyo3(name = "get_boosters")]
+    fn py_get_boosters(&self) -> Vec<Booster> {
+        self.get_boosters()
+    }
+
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+}
+
+#[pymethods]
+impl Booster {
+    #[staticmethod]
+    fn parse(name: &str) -> PyResult<Booster> {
+        name.parse().map_err(|_| PyValueError::new_err(format!("There's no booster called \"{}\".", name)))
+    }
+
+    #[staticmethod]
+    fn all() -> Vec<Booster> {
+        BOOSTERS.to_vec()
+    }
+
+    #[pyo3(name = "get_moves")]
+    fn py_get_moves(&self) -> Vec<Move> {
+        self.get_moves()
+    }
+
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+}
+
+#[pymethods]
+impl Move {
+    #[staticmethod]
+    fn parse(name: &str) -> PyResult<Move> {
+        name.parse().map_err(|_| PyValueError::new_err(format!("There's no move called \"{}\".", name)))
+    }
+
+    #[staticmethod]
+    fn all() -> Vec<Move> {
+        MOVES.to_vec()
+    }
+
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+}
+
+#[pymethods]
+impl Rules {
+    /// The standard rules, with any of the fields replaced (e.g., `Rules(points_to_win=3)`).
+    #[new]
+    #[pyo3(signature = (
+        *,
+        points_to_win = None,
+        tiebreak_points = None,
+        starting_waits = None,
+        character_streak_limit = None,
+        move_streak_limit = None,
+        nonexistent_penalty = None,
+        three_times_in_a_row_penalty = None,
+        wrong_character_penalty = None,
+        wrong_booster_penalty = None,
+        destroyed_move_penalty = None,
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    fn py_new(
+        points_to_win: Option<u8>,
+        tiebreak_points: Option<u8>,
+        starting_waits: Option<u8>,
+        character_streak_limit: Option<u8>,
+        move_streak_limit: Option<u8>,
+        nonexistent_penalty: Option<u8>,
+        three_times_in_a_row_penalty: Option<u8>,
+        wrong_character_penalty: Option<u8>,
+        wrong_booster_penalty: Option<u8>,
+        destroyed_move_penalty: Option<u8>,
+    ) -> Rules {
+        let standard = Rules::standard();
+
+        Rules {
+            points_to_win: points_to_win.unwrap_or(standard.points_to_win),
+            tiebreak_points: tiebreak_points.unwrap_or(standard.tiebreak_points),
+            starting_waits: starting_waits.unwrap_or(standard.starting_waits),
+            character_streak_limit: character_streak_limit.unwrap_or(standard.character_streak_limit),
+            move_streak_limit: move_streak_limit.unwrap_or(standard.move_streak_limit),
+            nonexistent_penalty: nonexistent_penalty.unwrap_or(standard.nonexistent_penalty),
+            three_times_in_a_row_penalty: three_times_in_a_row_penalty.unwrap_or(standard.three_times_in_a_row_penalty),
+            wrong_character_penalty: wrong_character_penalty.unwrap_or(standard.wrong_character_penalty),
+            wrong_booster_penalty: wrong_booster_penalty.unwrap_or(standard.wrong_booster_penalty),
+            destroyed_move_penalty: destroyed_move_penalty.unwrap_or(standard.destroyed_move_penalty),
+        }
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "standard")]
+    fn py_standard() -> Rules {
+        Rules::standard()
+    }
+
+    /// Raises `ValueError` if a game with these rules couldn't end.
+    #[pyo3(name = "validate")]
+    fn py_validate(&self) -> PyResult<()> {
+        self.validate().map_err(|error| PyValueError::new_err(error.to_string()))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+/// The built-in strategies the computer can use.
+#[pyclass(module = "nzsc_single_player", name = "Strategy", eq, eq_int, frozen, from_py_object)]
+#[derive(Clone, Copy, PartialEq)]
+pub enum PyStrategy {
+    Random,
+    Hard,
+    Nash,
+    Adaptive,
+}
+
+impl PyStrategy {
+    fn create(self) -> Box<dyn ComputerStrategy> {
+        match self {
+            PyStrategy::Random => Box::new(RandomStrategy),
+            PyStrategy::Hard => Box::new(HardStrategy::new()),
+            PyStrategy::Nash => Box::new(NashStrategy),
+            PyStrategy::Adaptive => Box::new(AdaptiveStrategy::new()),
+        }
+    }
+}
+
+/// Like `io::Question`.
+#[pyclass(module = "nzsc_single_player", name = "Question", frozen)]
+pub enum PyQuestion {
+    ChooseCharacter {
+        available_characters: Vec<Character>,
+    },
+    ChooseBooster {
+        available_boosters: Vec<Booster>,
+    },
+    ChooseMove {
+        available_moves: Vec<Move>,
+    },
+}
+
+/// Like `io::Notification`.
+#[pyclass(module = "nzsc_single_player", name = "Notification", frozen)]
+pub enum PyNotification {
+    CharacterSelectionAndHeadstart {
+        human_character: Character,
+        computer_character: Character,
+        who_gets_the_headstart: WhoGetsTheHeadstart,
+    },
+    SameCharacterSelection {
+        both_character: Character,
+    },
+    BoosterSelection {
+        human_booster: Booster,
+        computer_booster: Booster,
+    },
+    MoveSelectionAndOutcome {
+        human_move: Move,
+        computer_move: Move,
+        who_gets_the_point: WhoGetsThePoint,
+    },
+    ScoreUpdate {
+        human_points: u8,
+        computer_points: u8,
+    },
+    TiebreakingScoreSetback {
+        both_points: u8,
+    },
+    GameOver {
+        human_points: u8,
+        computer_points: u8,
+    },
+    CharacterNonexistentPenalty {
+        attempted_character_name: String,
+    },
+    CharacterThreeTimesInARowPenalty {
+        attempted_character: Character,
+    },
+    BoosterNonexistentPenalty {
+        attempted_booster_name: String,
+    },
+    BoosterFromWrongCharacterPenalty {
+        attempted_booster: Booster,
+    },
+    MoveNonexistentPenalty {
+        attempted_move_name: String,
+    },
+    MoveThreeTimesInARowPenalty {
+        attempted_move: Move,
+    },
+    MoveSingleUsePenalty {
+        attempted_move: Move,
+    },
+    MoveDestroyedPenalty {
+        attempted_move: Move,
+    },
+    MoveFromWrongCharacterPenalty {
+        attempted_move: Move,
+    },
+    MoveFromWrongBoosterPenalty {
+        attempted_move: Move,
+    },
+    NotEnoughWaits {},
+    OpponentPenalty {
+        penalty: Py<PyNotification>,
+    },
+}
+
+/// Like `io::Output`.
+#[pyclass(module = "nzsc_single_player", name = "Output", frozen, get_all)]
+pub struct PyOutput {
+    pub question: Option<Py<PyQuestion>>,
+    pub notifications: Vec<Py<PyNotification>>,
+}
+
+/// Like `history::HistoryEntry`.
+///
+/// `answer` is the chosen character, booster, or move, or the name typed if it doesn't exist,
+/// and `computer_choice` is a character, booster, move, or `None`.
+#[pyclass(module = "nzsc_single_player", name = "HistoryEntry", frozen, get_all)]
+pub struct PyHistoryEntry {
+    pub answer: Py<PyAny>,
+    pub computer_choice: Option<Py<PyAny>>,
+    pub notifications: Vec<Py<PyNotification>>,
+}
+
+/// A `SinglePlayerNZSCGame`, with the standard rules and a random computer unless others are given.
+#[pyclass(module = "nzsc_single_player", name = "SinglePlayerNZSCGame", unsendable)]
+pub struct PySinglePlayerNZSCGame {
+    game: SinglePlayerNZSCGame,
+}
+
+#[pymethods]
+impl PySinglePlayerNZSCGame {
+    /// Raises `ValueError` if `rules` aren't valid.
+    #[new]
+    #[pyo3(signature = (seed, rules = None, strategy = PyStrategy::Random))]
+    fn new(seed: u32, rules: Option<Rules>, strategy: PyStrategy) -> PyResult<PySinglePlayerNZSCGame> {
+        let rules = rules.unwrap_or_else(Rules::standard);
+        rules.validate().map_err(|error| PyValueError::new_err(error.to_string()))?;
+
+        Ok(PySinglePlayerNZSCGame {
+            game: SinglePlayerNZSCGame::with_strategy(seed, rules, strategy.create()),
+        })
+    }
+
+    #[getter]
+    fn seed(&self) -> u32 {
+        self.game.seed()
+    }
+
+    #[getter]
+    fn rules(&self) -> Rules {
+        self.game.rules()
+    }
+
+    /// Every accepted answer so far, oldest first.
+    fn history(&self, py: Python) -> PyResult<Vec<PyHistoryEntry>> {
+        self.game.history().iter()
+            .map(|entry| convert_history_entry(py, entry))
+            .collect()
+    }
+
+    fn current_output(&self, py: Python) -> PyResult<PyOutput> {
+        convert_output(py, self.game.current_output())
+    }
+
+    fn initial_output(&self, py: Python) -> PyResult<PyOutput> {
+        self.current_output(py)
+    }
+
+    fn choose_character(&mut self, py: Python, character: Character) -> PyResult<PyOutput> {
+        self.next(py, io::Answer::CharacterSelection(io::CharacterSelection::Character(character)))
+    }
+
+    fn choose_booster(&mut self, py: Python, booster: Booster) -> PyResult<PyOutput> {
+        self.next(py, io::Answer::BoosterSelection(io::BoosterSelection::Booster(booster)))
+    }
+
+    fn choose_move(&mut self, py: Python, selected_move: Move) -> PyResult<PyOutput> {
+        self.next(py, io::Answer::MoveSelection(io::MoveSelection::Move(selected_move)))
+    }
+
+    /// Answers the current question with text typed by the user (see `Question::parse_answer`).
+    fn answer_text(&mut self, py: Python, text: &str) -> PyResult<PyOutput> {
+        match self.game.current_output().question {
+            Some(question) => self.next(py, question.parse_answer(text)),
+            None => Err(PyValueError::new_err(super::errors::AnswerError::GameOver.to_string())),
+        }
+    }
+}
+
+impl PySinglePlayerNZSCGame {
+    fn next(&mut self, py: Python, answer: io::Answer) -> PyResult<PyOutput> {
+        let output = self.game.next(answer)
+            .map_err(|error| PyValueError::new_err(error.to_string()))?;
+
+        convert_output(py, output)
+    }
+}
+
+/// Like `outcomes::get_points`, but returns a list (PyO3 would convert a `Vec<u8>` to `bytes`).
+#[pyfunction]
+fn get_points(py: Python, moves: Vec<Move>) -> PyResult<Bound<PyList>> {
+    PyList::new(py, outcomes::get_points(moves))
+}
+
+/// Like `outcomes::get_move_points`.
+#[pyfunction]
+fn get_move_points(a_move: Move, a_booster: Booster, b_move: Move, b_booster: Booster) -> (u8, u8) {
+    outcomes::get_move_points(a_move, a_booster, b_move, b_booster)
+}
+
+/// Like `outcomes::get_headstart`, but returns a tuple.
+#[pyfunction]
+fn get_headstart(a: Character, b: Character) -> (u8, u8) {
+    let headstart = outcomes::get_headstart(a, b);
+
+    (headstart.0, headstart.1)
+}
+
+#[pymodule]
+pub fn nzsc_single_player(module: &Bound<PyModule>) -> PyResult<()> {
+    module.add_class::<Character>()?;
+    module.add_class::<Booster>()?;
+    module.add_class::<Move>()?;
+    module.add_class::<Rules>()?;
+    module.add_class::<PyStrategy>()?;
+    module.add_class::<WhoGetsThePoint>()?;
+    module.add_class::<WhoGetsTheHeadstart>()?;
+    module.add_class::<PyQuestion>()?;
+    module.add_class::<PyNotification>()?;
+    module.add_class::<PyOutput>()?;
+    module.add_class::<PyHistoryEntry>()?;
+    module.add_class::<PySinglePlayerNZSCGame>()?;
+    module.add_function(wrap_pyfunction!(self::get_points, module)?)?;
+    module.add_function(wrap_pyfunction!(self::get_move_points, module)?)?;
+    module.add_function(wrap_pyfunction!(self::get_headstart, module)?)?;
+
+    Ok(())
+}
+
+fn convert_output(py: Python, output: io::Output) -> PyResult<PyOutput> {
+    let question = match output.question {
+        Some(question) => Some(convert_question(question).into_pyobject(py)?.unbind()),
+        None => None,
+    };
+    let notifications = output.notifications.into_iter()
+        .map(|notification| Ok(convert_notification(py, notification)?.into_pyobject(py)?.unbind()))
+        .collect::<PyResult<_>>()?;
+
+    Ok(PyOutput {
+        question,
+        notifications,
+    })
+}
+
+fn convert_history_entry(py: Python, entry: &HistoryEntry) -> PyResult<PyHistoryEntry> {
+    let answer = match entry.answer {
+        io::Answer::CharacterSelection(io::CharacterSelection::Character(character)) => character.into_pyobject(py)?.into_any().unbind(),
+        io::Answer::BoosterSelection(io::BoosterSelection::Booster(booster)) => booster.into_pyobject(py)?.into_any().unbind(),
+        io::Answer::MoveSelection(io::MoveSelection::Move(selected_move)) => selected_move.into_pyobject(py)?.into_any().unbind(),
+        io::Answer::CharacterSelection(io::CharacterSelection::Nonexistent(ref name))
+        | io::Answer::BoosterSelection(io::BoosterSelection::Nonexistent(ref name))
+        | io::Answer::MoveSelection(io::MoveSelection::Nonexistent(ref name)) => name.into_pyobject(py)?.into_any().unbind(),
+    };
+    let computer_choice = match entry.computer_choice {
+        Some(ComputerChoice::Character(character)) => Some(character.into_pyobject(py)?.into_any().unbind()),
+        Some(ComputerChoice::Booster(booster)) => Some(booster.into_pyobject(py)?.into_any().unbind()),
+        Some(ComputerChoice::Move(selected_move)) => Some(selected_move.into_pyobject(py)?.into_any().unbind()),
+        None => None,
+    };
+    let notifications = entry.notifications.iter()
+        .map(|notification| Ok(convert_notification(py, notification.clone())?.into_pyobject(py)?.unbind()))
+        .collect::<PyResult<_>>()?;
+
+    Ok(PyHistoryEntry {
+        answer,
+        computer_choice,
+        notifications,
+    })
+}
+
+fn convert_question(question: io::Question) -> PyQuestion {
+    match question {
+        io::Question::ChooseCharacter { available_characters } => PyQuestion::ChooseCharacter { available_characters },
+        io::Question::ChooseBooster { available_boosters } => PyQuestion::ChooseBooster { available_boosters },
+        io::Question::ChooseMove { available_moves } => PyQuestion::ChooseMove { available_moves },
+    }
+}
+
+fn convert_notification(py: Python, notification: io::Notification) -> PyResult<PyNotification> {
+    let notification = match notification {
+        io::Notification::CharacterSelectionAndHeadstart { human_character, computer_character, who_gets_the_headstart } => {
+            PyNotification::CharacterSelectionAndHeadstart { human_character, computer_character, who_gets_the_headstart }
+        },
+        io::Notification::SameCharacterSelection { both_character } => {
+            PyNotification::SameCharacterSelection { both_character }
+        },
+        io::Notification::BoosterSelection { human_booster, computer_booster } => {
+            PyNotification::BoosterSelection { human_booster, computer_booster }
+        },
+        io::Notification::MoveSelectionAndOutcome { human_move, computer_move, who_gets_the_point } => {
+            PyNotification::MoveSelectionAndOutcome { human_move, computer_move, who_gets_the_point }
+        },
+        io::Notification::ScoreUpdate { human_points, computer_points } => {
+            PyNotification::ScoreUpdate { human_points, computer_points }
+        },
+        io::Notification::TiebreakingScoreSetback { both_points } => {
+            PyNotification::TiebreakingScoreSetback { both_points }
+        },
+        io::Notification::GameOver { human_points, computer_points } => {
+            PyNotification::GameOver { human_points, computer_points }
+        },
+        io::Notification::CharacterNonexistentPenalty { attempted_character_name } => {
+            PyNotification::CharacterNonexistentPenalty { attempted_character_name }
+        },
+        io::Notification::CharacterThreeTimesInARowPenalty { attempted_character } => {
+            PyNotification::CharacterThreeTimesInARowPenalty { attempted_character }
+        },
+        io::Notification::BoosterNonexistentPenalty { attempted_booster_name } => {
+            PyNotification::BoosterNonexistentPenalty { attempted_booster_name }
+        },
+        io::Notification::BoosterFromWrongCharacterPenalty { attempted_booster } => {
+            PyNotification::BoosterFromWrongCharacterPenalty { attempted_booster }
+        },
+        io::Notification::MoveNonexistentPenalty { attempted_move_name } => {
+            PyNotification::MoveNonexistentPenalty { attempted_move_name }
+        },
+        io::Notification::MoveThreeTimesInARowPenalty { attempted_move } => {
+            PyNotification::MoveThreeTimesInARowPenalty { attempted_move }
+        },
+        io::Notification::MoveSingleUsePenalty { attempted_move } => {
+            PyNotification::MoveSingleUsePenalty { attempted_move }
+        },
+        io::Notification::MoveDestroyedPenalty { attempted_move } => {
+            PyNotification::MoveDestroyedPenalty { attempted_move }
+        },
+        io::Notification::MoveFromWrongCharacterPenalty { attempted_move } => {
+            PyNotification::MoveFromWrongCharacterPenalty { attempted_move }
+        },
+        io::Notification::MoveFromWrongBoosterPenalty { attempted_move } => {
+            PyNotification::MoveFromWrongBoosterPenalty { attempted_move }
+        },
+        io::Notification::NotEnoughWaits => PyNotification::NotEnoughWaits {},
+        io::Notification::OpponentPenalty { penalty } => {
+            PyNotification::OpponentPenalty {
+                penalty: convert_notification(py, *penalty)?.into_pyobject(py)?.unbind(),
+            }
+        },
+    };
+
+    Ok(notification)
+}
@@ -0,0 +1,74 @@
+use super::errors::RulesError;
+
+/// The numbers that define a game of NZSC.
+///
+/// Penalties are measured in waits.
+/// When a player doesn't have enough waits left to pay a penalty,
+/// their opponent gets a point instead.
+///
+/// Games don't check their rules, so check custom rules with `validate` first.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "python", pyo3::pyclass(module = "nzsc_single_player", eq, get_all, set_all, from_py_object))]
+pub struct Rules {
+    /// The points a player needs to win.
+    pub points_to_win: u8,
+    /// If both players reach `points_to_win` at the same time, both are set back to this many points.
+    pub tiebreak_points: u8,
+    /// The waits each player starts with.
+    pub starting_waits: u8,
+    /// How many times in a row a player can choose the same character.
+    pub character_streak_limit: u8,
+    /// How many times in a row a player can choose the same move.
+    pub move_streak_limit: u8,
+
+    /// For choosing a character, booster, or move that doesn't exist.
+    pub nonexistent_penalty: u8,
+    /// For choosing a character or move more times in a row than allowed.
+    pub three_times_in_a_row_penalty: u8,
+    /// For choosing a booster or move of another character.
+    pub wrong_character_penalty: u8,
+    /// For choosing a move of another booster of your own character.
+    pub wrong_booster_penalty: u8,
+    /// For choosing a single-use move again, or a move that was destroyed.
+    pub destroyed_move_penalty: u8,
+}
+
+impl Rules {
+    /// The standard rules: first to 5 points wins.
+    pub fn standard() -> Rules {
+        Rules {
+            points_to_win: 5,
+            tiebreak_points: 4,
+            starting_waits: 4,
+            character_streak_limit: 3,
+            move_streak_limit: 3,
+
+            nonexistent_penalty: 4,
+            three_times_in_a_row_penalty: 3,
+            wrong_character_penalty: 3,
+            wrong_booster_penalty: 2,
+            destroyed_move_penalty: 4,
+        }
+    }
+
+    /// Checks that a game with these rules can end.
+    pub fn validate(&self) -> Result<(), RulesError> {
+        if self.points_to_win == 0 {
+            Err(RulesError::NoPointsToWin)
+        } else if self.tiebreak_points >= self.points_to_win {
+            Err(RulesError::TiebreakPointsTooHigh {
+                tiebreak_points: self.tiebreak_points,
+                points_to_win: self.points_to_win,
+            })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl Default for Rules {
+    fn default() -> Rules {
+        Rules::standard()
+    }
+}
@@ -0,0 +1,711 @@
+use super::{
+    Ruleset,
+    RulesetError,
+};
+use super::super::io::{
+    WhoGetsThePoint,
+    WhoGetsTheHeadstart,
+};
+use super::super::prfg;
+use super::super::rules::Rules;
+use super::super::errors::AnswerError;
+
+/// A player in a `CustomNZSCGame`.
+///
+/// Characters, boosters, and moves are indices into the game's `Ruleset`.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CustomPlayer {
+    pub points: u8,
+    pub waits: u8,
+    pub character: Option<usize>,
+    pub booster: Option<usize>,
+    pub character_streak: Streak,
+    pub move_streak: Streak,
+    pub destroyed_moves: Vec<usize>,
+}
+
+/// Keeps track of how many times a player chose a character or move in a row.
+#[derive(Clone, Copy, Default, Serialize, Deserialize)]
+pub struct Streak {
+    pub repeated: Option<usize>,
+    pub times: u8,
+}
+
+impl Streak {
+    fn add(&mut self, index: usize) {
+        self.times = if self.repeated == Some(index) {
+            self.times + 1
+        } else {
+            1
+        };
+        self.repeated = Some(index);
+    }
+
+    fn is_locked(&self, index: usize, limit: u8) -> bool {
+        self.repeated == Some(index) && self.times >= limit
+    }
+}
+
+impl CustomPlayer {
+    fn new(rules: &Rules) -> CustomPlayer {
+        CustomPlayer {
+            points: 0,
+            waits: rules.starting_waits,
+            character: None,
+            booster: None,
+            character_streak: Streak::default(),
+            move_streak: Streak::default(),
+            destroyed_moves: vec![],
+        }
+    }
+
+    fn penalize_waits(&mut self, waits: u8) -> u8 {
+        if self.waits < waits {
+            self.waits = 0;
+            1
+        } else {
+            self.waits -= waits;
+            0
+        }
+    }
+}
+
+/// A phase of a `CustomNZSCGame`.
+#[derive(Clone, Serialize, Deserialize)]
+pub enum Phase {
+    CharacterChoosing {
+        human: CustomPlayer,
+        computer: CustomPlayer,
+    },
+    BoosterChoosing {
+        human: CustomPlayer,
+        computer: CustomPlayer,
+    },
+    MoveChoosing {
+        human: CustomPlayer,
+        computer: CustomPlayer,
+    },
+    GameOver {
+        human_points: u8,
+        computer_points: u8,
+    },
+}
+
+/// Like `io::Output`, but with names from a `Ruleset`.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Output {
+    pub question: Option<Question>,
+    pub notifications: Vec<Notification>,
+}
+
+/// Like `io::Question`, but with names from a `Ruleset`.
+#[derive(Clone, Serialize, Deserialize)]
+pub enum Question {
+    ChooseCharacter {
+        available_characters: Vec<String>,
+    },
+    ChooseBooster {
+        available_boosters: Vec<String>,
+    },
+    ChooseMove {
+        available_moves: Vec<String>,
+    },
+}
+
+/// Like `io::Notification`, but with names from a `Ruleset`.
+#[derive(Clone, Serialize, Deserialize)]
+pub enum Notification {
+    CharacterSelectionAndHeadstart {
+        human_character: String,
+        computer_character: String,
+        who_gets_the_headstart: WhoGetsTheHeadstart,
+    },
+    SameCharacterSelection {
+        both_character: String,
+    },
+
+    BoosterSelection {
+        human_booster: String,
+        computer_booster: String,
+    },
+
+    MoveSelectionAndOutcome {
+        human_move: String,
+        computer_move: String,
+        who_gets_the_point: WhoGetsThePoint,
+    },
+
+    ScoreUpdate {
+        human_points: u8,
+        computer_points: u8,
+    },
+    TiebreakingScoreSetback {
+        both_points: u8,
+    },
+    GameOver {
+        human_points: u8,
+        computer_points: u8,
+    },
+
+    /// The human was penalized `waits` waits for choosing `attempted`.
+    Penalty {
+        reason: PenaltyReason,
+        attempted: String,
+        waits: u8,
+    },
+    /// The computer had no moves left (which the rules don't account for),
+    /// so it was penalized `waits` waits as if it chose a nonexistent move.
+    ComputerPenalty {
+        waits: u8,
+    },
+}
+
+/// Why a selection was penalized.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum PenaltyReason {
+    Nonexistent,
+    ThreeTimesInARow,
+    SingleUse,
+    Destroyed,
+    FromWrongCharacter,
+    FromWrongBooster,
+}
+
+/// The ruleset's names resolved to indices, so they don't have to be looked up every turn.
+#[derive(Clone)]
+struct Tables {
+    single_use: Vec<bool>,
+    destructive: Vec<bool>,
+    booster_moves: Vec<Vec<usize>>,
+    character_moves: Vec<Vec<usize>>,
+    character_boosters: Vec<Vec<usize>>,
+    /// `move_points[a][b]` is whether move `a` gets a point against move `b`.
+    move_points: Vec<Vec<bool>>,
+    /// `headstarts[a][b]` is whether character `a` gets a headstart against character `b`.
+    headstarts: Vec<Vec<bool>>,
+    /// `(booster, move, against, points)`
+    booster_outcomes: Vec<(usize, usize, usize, [u8; 2])>,
+}
+
+impl Tables {
+    fn new(ruleset: &Ruleset) -> Result<Tables, RulesetError> {
+        let move_indices = |names: &[String]| -> Result<Vec<usize>, RulesetError> {
+            names.iter().map(|name| ruleset.move_index(name)).collect()
+        };
+        let booster_indices = |names: &[String]| -> Result<Vec<usize>, RulesetError> {
+            names.iter().map(|name| ruleset.booster_index(name)).collect()
+        };
+
+        let mut move_points = vec![vec![false; ruleset.moves.len()]; ruleset.moves.len()];
+        for pair in &ruleset.move_points {
+            move_points[ruleset.move_index(&pair[0])?][ruleset.move_index(&pair[1])?] = true;
+        }
+
+        let mut headstarts = vec![vec![false; ruleset.characters.len()]; ruleset.characters.len()];
+        for pair in &ruleset.headstarts {
+            headstarts[ruleset.character_index(&pair[0])?][ruleset.character_index(&pair[1])?] = true;
+        }
+
+        let mut booster_outcomes = vec![];
+        for outcome in &ruleset.booster_outcomes {
+            booster_outcomes.push((
+                ruleset.booster_index(&outcome.booster)?,
+                ruleset.move_index(&outcome.move_name)?,
+                ruleset.move_index(&outcome.against)?,
+                outcome.points,
+            ));
+        }
+
+        Ok(Tables {
+            single_use: ruleset.moves.iter().map(|m| m.single_use).collect(),
+            destructive: ruleset.moves.iter().map(|m| m.destructive).collect(),
+            booster_moves: ruleset.boosters.iter()
+                .map(|b| move_indices(&b.moves))
+                .collect::<Result<_, _>>()?,
+            character_moves: ruleset.characters.iter()
+                .map(|c| move_indices(&c.moves))
+                .collect::<Result<_, _>>()?,
+            character_boosters: ruleset.characters.iter()
+                .map(|c| booster_indices(&c.boosters))
+                .collect::<Result<_, _>>()?,
+            move_points,
+            headstarts,
+            booster_outcomes,
+        })
+    }
+
+    fn headstart(&self, a: usize, b: usize) -> (u8, u8) {
+        (self.headstarts[a][b] as u8, self.headstarts[b][a] as u8)
+    }
+
+    fn move_points(&self, a_move: usize, a_booster: usize, b_move: usize, b_booster: usize) -> (u8, u8) {
+        for &(booster, move_index, against, points) in &self.booster_outcomes {
+            if booster == a_booster && move_index == a_move && against == b_move {
+                return (points[0], points[1]);
+            }
+            if booster == b_booster && move_index == b_move && against == a_move {
+                return (points[1], points[0]);
+            }
+        }
+
+        (self.move_points[a_move][b_move] as u8, self.move_points[b_move][a_move] as u8)
+    }
+}
+
+/// A single player game played with the content of a `Ruleset`.
+///
+/// The computer chooses randomly.
+pub struct CustomNZSCGame {
+    prfg: prfg::PseudorandomFloatGenerator,
+    rules: Rules,
+    ruleset: Ruleset,
+    tables: Tables,
+    pub phase: Phase,
+}
+
+impl CustomNZSCGame {
+    /// Fails if `rules` or `ruleset` isn't valid.
+    pub fn new(seed: u32, rules: Rules, ruleset: Ruleset) -> Result<CustomNZSCGame, RulesetError> {
+        rules.validate().map_err(RulesetError::InvalidRules)?;
+        ruleset.validate()?;
+        let tables = Tables::new(&ruleset)?;
+
+        Ok(CustomNZSCGame {
+            prfg: prfg::PseudorandomFloatGenerator::new(seed),
+            rules,
+            ruleset,
+            tables,
+            phase: Phase::CharacterChoosing {
+                human: CustomPlayer::new(&rules),
+                computer: CustomPlayer::new(&rules),
+            },
+        })
+    }
+
+    pub fn rules(&self) -> Rules {
+        self.rules
+    }
+
+    pub fn ruleset(&self) -> &Ruleset {
+        &self.ruleset
+    }
+
+    pub fn available_characters(&self, player: &CustomPlayer) -> Vec<usize> {
+        (0..self.ruleset.characters.len())
+            .filter(|&c| !player.character_streak.is_locked(c, self.rules.character_streak_limit))
+            .collect()
+    }
+
+    pub fn available_boosters(&self, player: &CustomPlayer) -> Vec<usize> {
+        match player.character {
+            Some(character) => self.tables.character_boosters[character].clone(),
+            None => vec![],
+        }
+    }
+
+    pub fn available_moves(&self, player: &CustomPlayer) -> Vec<usize> {
+        let mut moves = match player.character {
+            Some(character) => self.tables.character_moves[character].clone(),
+            None => vec![],
+        };
+        if let Some(booster) = player.booster {
+            moves.extend(&self.tables.booster_moves[booster]);
+        }
+
+        moves.retain(|&m| {
+            !player.destroyed_moves.contains(&m)
+                && !player.move_streak.is_locked(m, self.rules.move_streak_limit)
+        });
+
+        moves
+    }
+
+    /// The question currently being asked.
+    ///
+    /// If the game is over, there is no question, and the only notification is `GameOver`.
+    pub fn current_output(&self) -> Output {
+        match self.phase {
+            Phase::GameOver { human_points, computer_points } => Output {
+                question: None,
+                notifications: vec![
+                    Notification::GameOver {
+                        human_points,
+                        computer_points,
+                    }
+                ],
+            },
+            _ => Output {
+                question: self.question(),
+                notifications: vec![],
+            },
+        }
+    }
+
+    /// Answers the current question with the name (or alias) of a character, booster, or move.
+    ///
+    /// Names that don't exist are penalized like `Nonexistent` selections in the standard game.
+    pub fn next(&mut self, answer: &str) -> Result<Output, AnswerError> {
+        match self.phase.clone() {
+            Phase::CharacterChoosing { human, computer } => Ok(self.choose_character(answer, human, computer)),
+            Phase::BoosterChoosing { human, computer } => Ok(self.choose_booster(answer, human, computer)),
+            Phase::MoveChoosing { human, computer } => Ok(self.choose_move(answer, human, computer)),
+            Phase::GameOver { .. } => Err(AnswerError::GameOver),
+        }
+    }
+
+    fn question(&self) -> Option<Question> {
+        match self.phase {
+            Phase::CharacterChoosing { ref human, .. } => Some(Question::ChooseCharacter {
+                available_characters: self.character_names(&self.available_characters(human)),
+            }),
+            Phase::BoosterChoosing { ref human, .. } => Some(Question::ChooseBooster {
+                available_boosters: self.booster_names(&self.available_boosters(human)),
+            }),
+            Phase::MoveChoosing { ref human, .. } => Some(Question::ChooseMove {
+                available_moves: self.move_names(&self.available_moves(human)),
+            }),
+            Phase::GameOver { .. } => None,
+        }
+    }
+
+    fn choose_character(&mut self, answer: &str, mut human: CustomPlayer, mut computer: CustomPlayer) -> Output {
+        let human_character = match self.ruleset.character_index(answer) {
+            Ok(character) => character,
+            Err(_) => {
+                return self.penalize(PenaltyReason::Nonexistent, answer.to_string(), self.rules.nonexistent_penalty, human, computer);
+            },
+        };
+        if !self.available_characters(&human).contains(&human_character) {
+            let attempted = self.ruleset.characters[human_character].name.clone();
+            return self.penalize(PenaltyReason::ThreeTimesInARow, attempted, self.rules.three_times_in_a_row_penalty, human, computer);
+        }
+
+        let available_computer_characters = self.available_characters(&computer);
+        let computer_character = available_computer_characters[
+            self.prfg.generate_random_index_from_inclusive_max(available_computer_characters.len() - 1)
+        ];
+
+        human.character_streak.add(human_character);
+        computer.character_streak.add(computer_character);
+
+        if human_character == computer_character {
+            self.phase = Phase::CharacterChoosing {
+                human,
+                computer,
+            };
+
+            return Output {
+                question: self.question(),
+                notifications: vec![
+                    Notification::SameCharacterSelection {
+                        both_character: self.ruleset.characters[human_character].name.clone(),
+                    }
+                ],
+            };
+        }
+
+        let headstart = self.tables.headstart(human_character, computer_character);
+        human.points += headstart.0;
+        computer.points += headstart.1;
+        human.character = Some(human_character);
+        computer.character = Some(computer_character);
+
+        let who_gets_the_headstart = match headstart {
+            (0, 0) => WhoGetsTheHeadstart::Neither,
+            (0, 1) => WhoGetsTheHeadstart::JustComputer,
+            (1, 0) => WhoGetsTheHeadstart::JustHuman,
+            // Both characters getting headstarts cancel out.
+            _ => {
+                human.points -= 1;
+                computer.points -= 1;
+                WhoGetsTheHeadstart::Neither
+            },
+        };
+
+        let mut notifications = vec![
+            Notification::CharacterSelectionAndHeadstart {
+                human_character: self.ruleset.characters[human_character].name.clone(),
+                computer_character: self.ruleset.characters[computer_character].name.clone(),
+                who_gets_the_headstart,
+            }
+        ];
+
+        // Human might have incurred penalties before successfully choosing character,
+        // and either player's headstart might be enough to win.
+        if human.points < self.rules.points_to_win && computer.points < self.rules.points_to_win {
+            self.phase = Phase::BoosterChoosing {
+                human,
+                computer,
+            };
+        } else {
+            notifications.push(Notification::GameOver {
+                human_points: human.points,
+                computer_points: computer.points,
+            });
+            self.phase = Phase::GameOver {
+                human_points: human.points,
+                computer_points: computer.points,
+            };
+        }
+
+        Output {
+            question: self.question(),
+            notifications,
+        }
+    }
+
+    fn choose_booster(&mut self, answer: &str, mut human: CustomPlayer, mut computer: CustomPlayer) -> Output {
+        let human_booster = match self.ruleset.booster_index(answer) {
+            Ok(booster) => booster,
+            Err(_) => {
+                return self.penalize(PenaltyReason::Nonexistent, answer.to_string(), self.rules.nonexistent_penalty, human, computer);
+            },
+        };
+        if !self.available_boosters(&human).contains(&human_booster) {
+            let attempted = self.ruleset.boosters[human_booster].name.clone();
+            return self.penalize(PenaltyReason::FromWrongCharacter, attempted, self.rules.wrong_character_penalty, human, computer);
+        }
+
+        // Like the standard computer, this one doesn't choose boosters without moves (e.g., "No Booster"),
+        // unless there's nothing else to choose.
+        let all_computer_boosters = self.available_boosters(&computer);
+        let mut computer_boosters: Vec<usize> = all_computer_boosters.iter()
+            .cloned()
+            .filter(|&b| !self.tables.booster_moves[b].is_empty())
+            .collect();
+        if computer_boosters.is_empty() {
+            computer_boosters = all_computer_boosters;
+        }
+        let computer_booster = computer_boosters[
+            self.prfg.generate_random_index_from_inclusive_max(computer_boosters.len() - 1)
+        ];
+
+        human.booster = Some(human_booster);
+        computer.booster = Some(computer_booster);
+
+        self.phase = Phase::MoveChoosing {
+            human,
+            computer,
+        };
+
+        Output {
+            question: self.question(),
+            notifications: vec![
+                Notification::BoosterSelection {
+                    human_booster: self.ruleset.boosters[human_booster].name.clone(),
+                    computer_booster: self.ruleset.boosters[computer_booster].name.clone(),
+                }
+            ],
+        }
+    }
+
+    fn choose_move(&mut self, answer: &str, mut human: CustomPlayer, mut computer: CustomPlayer) -> Output {
+        let human_move = match self.ruleset.move_index(answer) {
+            Ok(human_move) => human_move,
+            Err(_) => {
+                return self.penalize(PenaltyReason::Nonexistent, answer.to_string(), self.rules.nonexistent_penalty, human, computer);
+            },
+        };
+        if !self.available_moves(&human).contains(&human_move) {
+            let (reason, waits) = self.move_penalty(&human, human_move);
+            let attempted = self.ruleset.moves[human_move].name.clone();
+            return self.penalize(reason, attempted, waits, human, computer);
+        }
+
+        let available_computer_moves = self.available_moves(&computer);
+        if available_computer_moves.is_empty() {
+            return self.penalize_computer(human, computer);
+        }
+        let computer_move = available_computer_moves[
+            self.prfg.generate_random_index_from_inclusive_max(available_computer_moves.len() - 1)
+        ];
+
+        human.move_streak.add(human_move);
+        computer.move_streak.add(computer_move);
+
+        if self.tables.single_use[human_move] || self.tables.destructive[computer_move] {
+            human.destroyed_moves.push(human_move);
+        }
+        if self.tables.single_use[computer_move] || self.tables.destructive[human_move] {
+            computer.destroyed_moves.push(computer_move);
+        }
+
+        let points = self.tables.move_points(
+            human_move,
+            human.booster.expect("Players choose boosters before moves"),
+            computer_move,
+            computer.booster.expect("Players choose boosters before moves"),
+        );
+        human.points += points.0;
+        computer.points += points.1;
+
+        let who_gets_the_point = match points {
+            (0, 0) => WhoGetsThePoint::Neither,
+            (0, 1) => WhoGetsThePoint::JustComputer,
+            (1, 0) => WhoGetsThePoint::JustHuman,
+            (1, 1) => WhoGetsThePoint::Both,
+            (a, b) => panic!("Illegal outcome: {}-{}!", a, b),
+        };
+
+        let mut notifications = vec![
+            Notification::MoveSelectionAndOutcome {
+                human_move: self.ruleset.moves[human_move].name.clone(),
+                computer_move: self.ruleset.moves[computer_move].name.clone(),
+                who_gets_the_point,
+            },
+            Notification::ScoreUpdate {
+                human_points: human.points,
+                computer_points: computer.points,
+            },
+        ];
+
+        if human.points >= self.rules.points_to_win || computer.points >= self.rules.points_to_win {
+            if human.points == computer.points {
+                notifications.push(Notification::TiebreakingScoreSetback {
+                    both_points: human.points,
+                });
+
+                human.points = self.rules.tiebreak_points;
+                computer.points = self.rules.tiebreak_points;
+
+                self.phase = Phase::MoveChoosing {
+                    human,
+                    computer,
+                };
+            } else {
+                notifications.push(Notification::GameOver {
+                    human_points: human.points,
+                    computer_points: computer.points,
+                });
+
+                self.phase = Phase::GameOver {
+                    human_points: human.points,
+                    computer_points: computer.points,
+                };
+            }
+        } else {
+            self.phase = Phase::MoveChoosing {
+                human,
+                computer,
+            };
+        }
+
+        Output {
+            question: self.question(),
+            notifications,
+        }
+    }
+
+    /// Why `human` may not choose `attempted_move`, and how many waits it costs.
+    fn move_penalty(&self, human: &CustomPlayer, attempted_move: usize) -> (PenaltyReason, u8) {
+        if human.destroyed_moves.contains(&attempted_move) {
+            if self.tables.single_use[attempted_move] {
+                (PenaltyReason::SingleUse, self.rules.destroyed_move_penalty)
+            } else {
+                (PenaltyReason::Destroyed, self.rules.destroyed_move_penalty)
+            }
+        } else if human.move_streak.is_locked(attempted_move, self.rules.move_streak_limit) {
+            (PenaltyReason::ThreeTimesInARow, self.rules.three_times_in_a_row_penalty)
+        } else {
+            let is_from_own_booster = self.available_boosters(human).iter()
+                .any(|&b| self.tables.booster_moves[b].contains(&attempted_move));
+
+            if is_from_own_booster {
+                (PenaltyReason::FromWrongBooster, self.rules.wrong_booster_penalty)
+            } else {
+                (PenaltyReason::FromWrongCharacter, self.rules.wrong_character_penalty)
+            }
+        }
+    }
+
+    /// Takes `waits` waits from the human (or gives the computer a point), and asks the same question again.
+    fn penalize(&mut self, reason: PenaltyReason, attempted: String, waits: u8, mut human: CustomPlayer, mut computer: CustomPlayer) -> Output {
+        computer.points += human.penalize_waits(waits);
+
+        let mut notifications = vec![
+            Notification::Penalty {
+                reason,
+                attempted,
+                waits,
+            },
+            Notification::ScoreUpdate {
+                human_points: human.points,
+                computer_points: computer.points,
+            },
+        ];
+
+        if computer.points < self.rules.points_to_win {
+            self.phase = match self.phase {
+                Phase::CharacterChoosing { .. } => Phase::CharacterChoosing { human, computer },
+                Phase::BoosterChoosing { .. } => Phase::BoosterChoosing { human, computer },
+                Phase::MoveChoosing { .. } => Phase::MoveChoosing { human, computer },
+                Phase::GameOver { .. } => unreachable!("Answers aren't accepted once the game is over"),
+            };
+        } else {
+            notifications.push(Notification::GameOver {
+                human_points: human.points,
+                computer_points: computer.points,
+            });
+
+            self.phase = Phase::GameOver {
+                human_points: human.points,
+                computer_points: computer.points,
+            };
+        }
+
+        Output {
+            question: self.question(),
+            notifications,
+        }
+    }
+
+    /// Takes the nonexistent penalty from the computer (or gives the human a point), and asks the same question again.
+    fn penalize_computer(&mut self, mut human: CustomPlayer, mut computer: CustomPlayer) -> Output {
+        let waits = self.rules.nonexistent_penalty;
+        human.points += computer.penalize_waits(waits);
+
+        let mut notifications = vec![
+            Notification::ComputerPenalty {
+                waits,
+            },
+            Notification::ScoreUpdate {
+                human_points: human.points,
+                computer_points: computer.points,
+            },
+        ];
+
+        if human.points < self.rules.points_to_win {
+            self.phase = Phase::MoveChoosing {
+                human,
+                computer,
+            };
+        } else {
+            notifications.push(Notification::GameOver {
+                human_points: human.points,
+                computer_points: computer.points,
+            });
+
+            self.phase = Phase::GameOver {
+                human_points: human.points,
+                computer_points: computer.points,
+            };
+        }
+
+        Output {
+            question: self.question(),
+            notifications,
+        }
+    }
+
+    fn character_names(&self, characters: &[usize]) -> Vec<String> {
+        characters.iter().map(|&c| self.ruleset.characters[c].name.clone()).collect()
+    }
+
+    fn booster_names(&self, boosters: &[usize]) -> Vec<String> {
+        boosters.iter().map(|&b| self.ruleset.boosters[b].name.clone()).collect()
+    }
+
+    fn move_names(&self, moves: &[usize]) -> Vec<String> {
+        moves.iter().map(|&m| self.ruleset.moves[m].name.clone()).collect()
+    }
+}
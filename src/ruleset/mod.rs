@@ -0,0 +1,347 @@
+//! Characters, boosters, moves, and outcomes defined by data instead of code.
+//!
+//! A `Ruleset` can be loaded from JSON, so new content can be prototyped without recompiling.
+//! `CustomNZSCGame` plays a game with any valid `Ruleset`.
+//! `Ruleset::standard()` describes the same content as the built-in enums and tables,
+//! so `Ruleset::standard().to_json()` is a good starting point for new rulesets.
+//!
+//! `CustomNZSCGame` is a separate engine from `SinglePlayerNZSCGame`, since the rest of the crate
+//! is built on the `Character`, `Booster`, and `Move` enums. So custom games don't have
+//! computer strategies (the computer always chooses randomly), undo, history or replays,
+//! locale rendering, or the advisor, and their outputs are this module's own `Output`, `Question`, and `Notification`.
+
+use std::error::Error;
+use std::fmt;
+
+use serde_json;
+
+use super::helpers;
+use super::characters::Character;
+use super::boosters::Booster;
+use super::moves::{
+    Move,
+    SINGLE_USE_MOVES,
+    DESTRUCTIVE_MOVES,
+};
+use super::outcomes;
+use super::errors::RulesError;
+
+pub use self::game::{
+    CustomNZSCGame,
+    Phase,
+    CustomPlayer,
+    Streak,
+    Output,
+    Question,
+    Notification,
+    PenaltyReason,
+};
+
+mod game;
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Ruleset {
+    pub moves: Vec<MoveDefinition>,
+    pub boosters: Vec<BoosterDefinition>,
+    pub characters: Vec<CharacterDefinition>,
+    /// Each pair `[a, b]` means move `a` gets a point when played against move `b`.
+    #[serde(default)]
+    pub move_points: Vec<[String; 2]>,
+    /// Each pair `[a, b]` means character `a` gets a headstart against character `b`.
+    #[serde(default)]
+    pub headstarts: Vec<[String; 2]>,
+    /// Outcomes that depend on boosters, which replace `move_points` when they apply.
+    #[serde(default)]
+    pub booster_outcomes: Vec<BoosterOutcome>,
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct MoveDefinition {
+    pub name: String,
+    /// Other names players can type to choose this move.
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    /// Whether a player can only use this move once.
+    #[serde(default)]
+    pub single_use: bool,
+    /// Whether this move destroys whatever move it is played against.
+    #[serde(default)]
+    pub destructive: bool,
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct BoosterDefinition {
+    pub name: String,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    pub moves: Vec<String>,
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct CharacterDefinition {
+    pub name: String,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    pub moves: Vec<String>,
+    pub boosters: Vec<String>,
+}
+
+/// When a player with `booster` plays `move_name` against `against`,
+/// they get `points[0]` points and their opponent gets `points[1]` points.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct BoosterOutcome {
+    pub booster: String,
+    #[serde(rename = "move")]
+    pub move_name: String,
+    pub against: String,
+    pub points: [u8; 2],
+}
+
+/// Why a `Ruleset` couldn't be loaded.
+#[derive(Debug)]
+pub enum RulesetError {
+    /// The ruleset isn't valid JSON, or doesn't have the right fields.
+    Parse(serde_json::Error),
+    /// Two entities of the same kind have the same name (or alias).
+    DuplicateName(String),
+    /// A name doesn't refer to any entity of the expected kind.
+    UnknownName {
+        kind: &'static str,
+        name: String,
+    },
+    /// There must be at least two characters, or characters could be tied forever.
+    TooFewCharacters,
+    /// A character has no boosters to choose from.
+    NoBoosters(String),
+    /// A character has no moves when using one of their boosters.
+    NoMoves {
+        character: String,
+        booster: String,
+    },
+    /// A booster outcome gives a player more than one point.
+    TooManyPoints,
+    /// The `Rules` a game was created with can't be played with.
+    InvalidRules(RulesError),
+}
+
+impl fmt::Display for RulesetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RulesetError::Parse(ref error) => write!(f, "Invalid ruleset: {}", error),
+            RulesetError::DuplicateName(ref name) => write!(f, "The name \"{}\" is used more than once.", name),
+            RulesetError::UnknownName { kind, ref name } => write!(f, "There is no {} named \"{}\".", kind, name),
+            RulesetError::TooFewCharacters => write!(f, "There must be at least two characters."),
+            RulesetError::NoBoosters(ref name) => write!(f, "The character \"{}\" has no boosters.", name),
+            RulesetError::NoMoves { ref character, ref booster } => {
+                write!(f, "The character \"{}\" has no moves with the booster \"{}\".", character, booster)
+            },
+            RulesetError::TooManyPoints => write!(f, "A move can't get more than one point."),
+            RulesetError::InvalidRules(ref error) => write!(f, "Invalid rules: {}", error),
+        }
+    }
+}
+
+impl Error for RulesetError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            RulesetError::Parse(ref error) => Some(error),
+            RulesetError::InvalidRules(ref error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl Ruleset {
+    /// Loads and validates a ruleset written in JSON.
+    pub fn from_json(json: &str) -> Result<Ruleset, RulesetError> {
+        let ruleset: Ruleset = serde_json::from_str(json).map_err(RulesetError::Parse)?;
+
+        ruleset.validate()?;
+
+        Ok(ruleset)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Rulesets can always be serialized")
+    }
+
+    /// The content of standard NZSC.
+    pub fn standard() -> Ruleset {
+        let characters = vec![
+            Character::Ninja,
+            Character::Zombie,
+            Character::Samurai,
+            Character::Clown,
+        ];
+        let mut boosters: Vec<Booster> = vec![];
+        let mut moves: Vec<Move> = vec![];
+        for &character in &characters {
+            moves.extend(character.get_moves());
+            for booster in character.get_boosters() {
+                if !boosters.contains(&booster) {
+                    boosters.push(booster);
+                    moves.extend(booster.get_moves());
+                }
+            }
+        }
+        moves.sort_by_key(|&m| m.to_u8());
+        moves.dedup();
+
+        let mut move_points = vec![];
+        for &a in &moves {
+            for &b in &moves {
+                // Booster-dependent outcomes assume neither player has a special booster.
+                if outcomes::get_move_points(a, Booster::None, b, Booster::None).0 == 1 {
+                    move_points.push([a.to_string(), b.to_string()]);
+                }
+            }
+        }
+
+        let mut headstarts = vec![];
+        for &a in &characters {
+            for &b in &characters {
+                if outcomes::get_headstart(a, b).0 == 1 {
+                    headstarts.push([a.to_string(), b.to_string()]);
+                }
+            }
+        }
+
+        Ruleset {
+            moves: moves.iter()
+                .map(|&m| MoveDefinition {
+                    name: m.to_string(),
+                    aliases: vec![],
+                    single_use: SINGLE_USE_MOVES.contains(&m),
+                    destructive: DESTRUCTIVE_MOVES.contains(&m),
+                })
+                .collect(),
+            boosters: boosters.iter()
+                .map(|&booster| BoosterDefinition {
+                    name: booster.to_string(),
+                    aliases: if booster == Booster::None { vec!["None".to_string()] } else { vec![] },
+                    moves: booster.get_moves().iter().map(|m| m.to_string()).collect(),
+                })
+                .collect(),
+            characters: characters.iter()
+                .map(|&character| CharacterDefinition {
+                    name: character.to_string(),
+                    aliases: vec![],
+                    moves: character.get_moves().iter().map(|m| m.to_string()).collect(),
+                    boosters: character.get_boosters().iter().map(|b| b.to_string()).collect(),
+                })
+                .collect(),
+            move_points,
+            headstarts,
+            booster_outcomes: vec![
+                BoosterOutcome {
+                    booster: Booster::Strong.to_string(),
+                    move_name: Move::Smash.to_string(),
+                    against: Move::ShadowFireball.to_string(),
+                    points: [1, 0],
+                },
+            ],
+        }
+    }
+
+    /// Checks that every name refers to something, and that the ruleset is playable.
+    pub fn validate(&self) -> Result<(), RulesetError> {
+        check_unique(self.moves.iter().map(|m| (&m.name, &m.aliases)))?;
+        check_unique(self.boosters.iter().map(|b| (&b.name, &b.aliases)))?;
+        check_unique(self.characters.iter().map(|c| (&c.name, &c.aliases)))?;
+
+        if self.characters.len() < 2 {
+            return Err(RulesetError::TooFewCharacters);
+        }
+
+        for booster in &self.boosters {
+            for name in &booster.moves {
+                self.move_index(name)?;
+            }
+        }
+        for character in &self.characters {
+            if character.boosters.is_empty() {
+                return Err(RulesetError::NoBoosters(character.name.clone()));
+            }
+            for name in &character.moves {
+                self.move_index(name)?;
+            }
+            for name in &character.boosters {
+                let booster = &self.boosters[self.booster_index(name)?];
+                if character.moves.is_empty() && booster.moves.is_empty() {
+                    return Err(RulesetError::NoMoves {
+                        character: character.name.clone(),
+                        booster: booster.name.clone(),
+                    });
+                }
+            }
+        }
+        for pair in &self.move_points {
+            self.move_index(&pair[0])?;
+            self.move_index(&pair[1])?;
+        }
+        for pair in &self.headstarts {
+            self.character_index(&pair[0])?;
+            self.character_index(&pair[1])?;
+        }
+        for outcome in &self.booster_outcomes {
+            self.booster_index(&outcome.booster)?;
+            self.move_index(&outcome.move_name)?;
+            self.move_index(&outcome.against)?;
+            if outcome.points[0] > 1 || outcome.points[1] > 1 {
+                return Err(RulesetError::TooManyPoints);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Finds the move named (or aliased) `name`, ignoring case and whitespace.
+    pub fn move_index(&self, name: &str) -> Result<usize, RulesetError> {
+        find(self.moves.iter().map(|m| (&m.name, &m.aliases)), name)
+            .ok_or_else(|| RulesetError::UnknownName { kind: "move", name: name.to_string() })
+    }
+
+    /// Finds the booster named (or aliased) `name`, ignoring case and whitespace.
+    pub fn booster_index(&self, name: &str) -> Result<usize, RulesetError> {
+        find(self.boosters.iter().map(|b| (&b.name, &b.aliases)), name)
+            .ok_or_else(|| RulesetError::UnknownName { kind: "booster", name: name.to_string() })
+    }
+
+    /// Finds the character named (or aliased) `name`, ignoring case and whitespace.
+    pub fn character_index(&self, name: &str) -> Result<usize, RulesetError> {
+        find(self.characters.iter().map(|c| (&c.name, &c.aliases)), name)
+            .ok_or_else(|| RulesetError::UnknownName { kind: "character", name: name.to_string() })
+    }
+}
+
+fn find<'a, I>(entities: I, name: &str) -> Option<usize>
+    where I: Iterator<Item = (&'a String, &'a Vec<String>)>
+{
+    let name = helpers::lowercase_no_whitespace(name);
+
+    entities
+        .enumerate()
+        .find(|&(_, (entity_name, aliases))| {
+            helpers::lowercase_no_whitespace(entity_name) == name
+                || aliases.iter().any(|alias| helpers::lowercase_no_whitespace(alias) == name)
+        })
+        .map(|(index, _)| index)
+}
+
+fn check_unique<'a, I>(entities: I) -> Result<(), RulesetError>
+    where I: Iterator<Item = (&'a String, &'a Vec<String>)>
+{
+    let mut seen: Vec<String> = vec![];
+
+    for (name, aliases) in entities {
+        for name in Some(name).into_iter().chain(aliases) {
+            let normalized = helpers::lowercase_no_whitespace(name);
+            if seen.contains(&normalized) {
+                return Err(RulesetError::DuplicateName(name.clone()));
+            }
+            seen.push(normalized);
+        }
+    }
+
+    Ok(())
+}
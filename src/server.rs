@@ -0,0 +1,392 @@
+//! A line-delimited JSON protocol for driving games from other processes.
+//!
+//! Each request is one line of JSON naming a session and a command, and gets exactly one line back.
+//! A server hosts any number of sessions, each of which is a `SinglePlayerNZSCGame` with its own id.
+//! If `session` is omitted, it's `"default"`.
+//!
+//! | Request | Response |
+//! |---|---|
+//! | `{"session": "a", "new": {"seed": 42}}` | `{"session": "a", "output": <Output>}` |
+//! | `{"session": "a", "answer": <Answer>}` | `{"session": "a", "output": <Output>}` |
+//! | `{"session": "a", "text": "ninja sword"}` | `{"session": "a", "output": <Output>}` |
+//! | `{"session": "a", "output": {}}` | `{"session": "a", "output": <Output>}` |
+//! | `{"session": "a", "close": {}}` | `{"session": "a", "closed": {}}` |
+//!
+//! `<Output>` and `<Answer>` are the `serde` representations of `io::Output` and `io::Answer`
+//! (e.g., `{"CharacterSelection": {"Character": "Ninja"}}`).
+//! `new` starts a game (with a seed from the clock, if `seed` is omitted), and fails if the session already exists.
+//! `text` answers with text typed by the user (see `Question::parse_answer`).
+//! `output` gets the current output without answering.
+//!
+//! A request that fails gets `{"session": "a", "error": "<message>"}`, and doesn't change the session.
+//! If the request isn't valid JSON, `session` is `null`.
+//!
+//! A server hosts at most `DEFAULT_MAX_SESSIONS` sessions at a time, and closes sessions that haven't had a request
+//! for `DEFAULT_IDLE_TIMEOUT` (see `Server::with_max_sessions` and `Server::with_idle_timeout`).
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::io::{
+    self,
+    BufRead,
+    BufReader,
+    Read,
+    Write,
+};
+use std::net::TcpListener;
+#[cfg(unix)]
+use std::os::unix::net::UnixListener;
+use std::sync::mpsc::{
+    self,
+    Sender,
+};
+use std::thread;
+use std::time::{
+    Duration,
+    Instant,
+};
+
+use serde_json;
+
+use super::single_player_game::SinglePlayerNZSCGame;
+use super::prfg::PseudorandomFloatGenerator;
+use super::rules::Rules;
+use super::errors::AnswerError;
+use super::io::{
+    Answer,
+    Output,
+};
+
+#[derive(Clone)]
+#[derive(Serialize, Deserialize)]
+pub struct Request {
+    #[serde(default = "default_session")]
+    pub session: String,
+    #[serde(flatten)]
+    pub command: Command,
+}
+
+#[derive(Clone)]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Command {
+    New {
+        seed: Option<u32>,
+    },
+    Answer(Answer),
+    Text(String),
+    Output {},
+    Close {},
+}
+
+#[derive(Clone)]
+#[derive(Serialize, Deserialize)]
+pub struct Response {
+    pub session: Option<String>,
+    #[serde(flatten)]
+    pub reply: Reply,
+}
+
+#[derive(Clone)]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Reply {
+    Output(Output),
+    Closed {},
+    Error(String),
+}
+
+/// Why a request failed.
+#[derive(Debug)]
+pub enum ServerError {
+    /// The request isn't valid JSON, or isn't a valid request.
+    InvalidRequest(serde_json::Error),
+    SessionNotFound(String),
+    SessionAlreadyExists(String),
+    /// A new session was requested, but the server already hosts this many.
+    TooManySessions(usize),
+    /// A connection sent a line longer than this many bytes, so it was closed.
+    RequestTooLong(usize),
+    Answer(AnswerError),
+}
+
+impl fmt::Display for ServerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ServerError::InvalidRequest(ref error) => write!(f, "Invalid request: {}", error),
+            ServerError::SessionNotFound(ref id) => write!(f, "There is no session \"{}\".", id),
+            ServerError::SessionAlreadyExists(ref id) => write!(f, "The session \"{}\" already exists.", id),
+            ServerError::TooManySessions(max) => write!(f, "The server already hosts {} sessions. Close one first.", max),
+            ServerError::RequestTooLong(max) => write!(f, "Requests can't be longer than {} bytes.", max),
+            ServerError::Answer(ref error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for ServerError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            ServerError::InvalidRequest(ref error) => Some(error),
+            ServerError::Answer(ref error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// How many sessions a server hosts at a time by default.
+pub const DEFAULT_MAX_SESSIONS: usize = 1024;
+/// How long a session can go without requests before it's closed by default.
+pub const DEFAULT_IDLE_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+
+/// The sessions hosted by a server.
+pub struct Server {
+    rules: Rules,
+    max_sessions: usize,
+    idle_timeout: Duration,
+    sessions: HashMap<String, Session>,
+}
+
+struct Session {
+    game: SinglePlayerNZSCGame,
+    last_request: Instant,
+}
+
+impl Default for Server {
+    fn default() -> Server {
+        Server::new(Rules::standard())
+    }
+}
+
+impl Server {
+    /// Creates a server whose games use `rules`.
+    pub fn new(rules: Rules) -> Server {
+        Server {
+            rules,
+            max_sessions: DEFAULT_MAX_SESSIONS,
+            idle_timeout: DEFAULT_IDLE_TIMEOUT,
+            sessions: HashMap::new(),
+        }
+    }
+
+    /// Limits the server to `max_sessions` sessions at a time. Once it's reached, `new` fails until a session is closed.
+    pub fn with_max_sessions(self, max_sessions: usize) -> Server {
+        Server {
+            max_sessions,
+            ..self
+        }
+    }
+
+    /// Closes sessions once they've gone `idle_timeout` without a request.
+    pub fn with_idle_timeout(self, idle_timeout: Duration) -> Server {
+        Server {
+            idle_timeout,
+            ..self
+        }
+    }
+
+    /// The ids of the open sessions, in no particular order.
+    pub fn session_ids(&self) -> Vec<String> {
+        self.sessions.keys().cloned().collect()
+    }
+
+    pub fn handle(&mut self, request: Request) -> Response {
+        self.close_idle_sessions();
+        let reply = match self.execute(&request.session, request.command) {
+            Ok(reply) => reply,
+            Err(error) => Reply::Error(error.to_string()),
+        };
+
+        Response {
+            session: Some(request.session),
+            reply,
+        }
+    }
+
+    /// Handles a request written in JSON, and returns the response written in JSON.
+    pub fn handle_line(&mut self, line: &str) -> String {
+        let response = match serde_json::from_str(line) {
+            Ok(request) => self.handle(request),
+            Err(error) => Response {
+                session: None,
+                reply: Reply::Error(ServerError::InvalidRequest(error).to_string()),
+            },
+        };
+
+        serde_json::to_string(&response).expect("Responses can always be serialized")
+    }
+
+    fn execute(&mut self, id: &str, command: Command) -> Result<Reply, ServerError> {
+        match command {
+            Command::New { seed } => {
+                if self.sessions.contains_key(id) {
+                    return Err(ServerError::SessionAlreadyExists(id.to_string()));
+                }
+                if self.sessions.len() >= self.max_sessions {
+                    return Err(ServerError::TooManySessions(self.max_sessions));
+                }
+
+                let game = SinglePlayerNZSCGame::new(seed.unwrap_or_else(PseudorandomFloatGenerator::clock_seed), self.rules);
+                let output = game.current_output();
+                self.sessions.insert(id.to_string(), Session {
+                    game,
+                    last_request: Instant::now(),
+                });
+
+                Ok(Reply::Output(output))
+            },
+            Command::Answer(answer) => {
+                let game = self.session(id)?;
+
+                game.next(answer).map(Reply::Output).map_err(ServerError::Answer)
+            },
+            Command::Text(text) => {
+                let game = self.session(id)?;
+                let answer = match game.current_output().question {
+                    Some(question) => question.parse_answer(&text),
+                    None => return Err(ServerError::Answer(AnswerError::GameOver)),
+                };
+
+                game.next(answer).map(Reply::Output).map_err(ServerError::Answer)
+            },
+            Command::Output {} => Ok(Reply::Output(self.session(id)?.current_output())),
+            Command::Close {} => {
+                match self.sessions.remove(id) {
+                    Some(_) => Ok(Reply::Closed {}),
+                    None => Err(ServerError::SessionNotFound(id.to_string())),
+                }
+            },
+        }
+    }
+
+    fn session(&mut self, id: &str) -> Result<&mut SinglePlayerNZSCGame, ServerError> {
+        let session = self.sessions.get_mut(id).ok_or_else(|| ServerError::SessionNotFound(id.to_string()))?;
+        session.last_request = Instant::now();
+
+        Ok(&mut session.game)
+    }
+
+    fn close_idle_sessions(&mut self) {
+        let idle_timeout = self.idle_timeout;
+
+        self.sessions.retain(|_, session| session.last_request.elapsed() < idle_timeout);
+    }
+}
+
+/// Answers each line of `reader` on a line of `writer` until `reader` ends (e.g., with stdin and stdout).
+///
+/// Blank lines are ignored.
+pub fn serve<R: BufRead, W: Write>(server: &mut Server, reader: R, mut writer: W) -> io::Result<()> {
+    for line in reader.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        writeln!(writer, "{}", server.handle_line(&line))?;
+        writer.flush()?;
+    }
+
+    Ok(())
+}
+
+/// Serves every connection to `listener` (each of which works like `serve`). Never returns.
+///
+/// Connections are handled concurrently, but share sessions,
+/// so a client can reconnect and continue a session.
+pub fn serve_tcp(server: &mut Server, listener: TcpListener) {
+    serve_connections(server, move || listener.accept().map(|(stream, _)| stream));
+}
+
+/// Like `serve_tcp`, but for a Unix domain socket.
+#[cfg(unix)]
+pub fn serve_unix(server: &mut Server, listener: UnixListener) {
+    serve_connections(server, move || listener.accept().map(|(stream, _)| stream));
+}
+
+type Envelope = (String, Sender<String>);
+
+/// The longest line a connection can send. Longer lines close the connection,
+/// so clients can't make the server buffer unlimited data.
+pub const MAX_REQUEST_BYTES: usize = 64 * 1024;
+
+const MIN_ACCEPT_BACKOFF: Duration = Duration::from_millis(10);
+const MAX_ACCEPT_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Every connection shares `server`, which is only borrowed, so connections are read on their own threads
+/// and their requests are handled one at a time on the current thread (which also means sessions don't need locks).
+fn serve_connections<A, S>(server: &mut Server, mut accept: A)
+    where A: FnMut() -> io::Result<S> + Send + 'static,
+          S: Read + Write + Send + 'static
+{
+    let (sender, receiver) = mpsc::channel::<Envelope>();
+
+    thread::spawn(move || {
+        let mut backoff = MIN_ACCEPT_BACKOFF;
+
+        loop {
+            match accept() {
+                Ok(stream) => {
+                    backoff = MIN_ACCEPT_BACKOFF;
+                    let sender = sender.clone();
+                    thread::spawn(move || forward(stream, &sender));
+                },
+                // A failed connection shouldn't stop the server, but if accepting keeps failing
+                // (e.g., because it's out of file descriptors), wait longer and longer before trying again.
+                Err(error) => {
+                    eprintln!("Couldn't accept a connection: {} (retrying in {:?})", error, backoff);
+                    thread::sleep(backoff);
+                    backoff = (backoff * 2).min(MAX_ACCEPT_BACKOFF);
+                },
+            }
+        }
+    });
+
+    for (line, reply) in receiver {
+        // The connection may have closed in the meantime.
+        let _ = reply.send(server.handle_line(&line));
+    }
+}
+
+fn forward<S: Read + Write>(stream: S, requests: &Sender<Envelope>) -> io::Result<()> {
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+
+    loop {
+        line.clear();
+        // Read one byte more than allowed, to tell a line that's exactly the limit from one that's too long.
+        let read = Read::by_ref(&mut reader).take(MAX_REQUEST_BYTES as u64 + 1).read_line(&mut line)?;
+        if read == 0 {
+            return Ok(());
+        }
+        if read > MAX_REQUEST_BYTES && !line.ends_with('\n') {
+            let response = Response {
+                session: None,
+                reply: Reply::Error(ServerError::RequestTooLong(MAX_REQUEST_BYTES).to_string()),
+            };
+            let stream = reader.get_mut();
+            writeln!(stream, "{}", serde_json::to_string(&response).expect("Responses can always be serialized"))?;
+            return stream.flush();
+        }
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let (reply_sender, reply_receiver) = mpsc::channel();
+        if requests.send((line.clone(), reply_sender)).is_err() {
+            return Ok(());
+        }
+        let response = match reply_receiver.recv() {
+            Ok(response) => response,
+            Err(_) => return Ok(()),
+        };
+
+        let stream = reader.get_mut();
+        writeln!(stream, "{}", response)?;
+        stream.flush()?;
+    }
+}
+
+fn default_session() -> String {
+    "default".to_string()
+}
@@ -0,0 +1,378 @@
+//! Plays strategies against each other over many seeded games,
+//! for balancing difficulty levels and catching rule regressions.
+
+use std::fmt;
+use std::ops::AddAssign;
+
+use super::two_player_game::{
+    TwoPlayerNZSCGame,
+    Phase,
+};
+use super::strategies::{
+    self,
+    ComputerStrategy,
+};
+use super::history::{
+    ComputerChoice,
+    HistoryEntry,
+};
+use super::players::Player;
+use super::prfg::PseudorandomFloatGenerator;
+use super::rules::Rules;
+use super::io;
+
+/// One of the two strategies in a simulation.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Side {
+    A,
+    B,
+}
+
+/// How many times a player was penalized for each kind of illegal selection.
+///
+/// The kinds correspond to the penalties in `Rules`.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PenaltyCounts {
+    pub nonexistent: u32,
+    pub three_times_in_a_row: u32,
+    pub wrong_character: u32,
+    pub wrong_booster: u32,
+    pub destroyed: u32,
+}
+
+impl PenaltyCounts {
+    pub fn total(&self) -> u32 {
+        self.nonexistent + self.three_times_in_a_row + self.wrong_character + self.wrong_booster + self.destroyed
+    }
+
+    /// Counts `notification` if it's a penalty.
+    fn record(&mut self, notification: &io::Notification) {
+        match *notification {
+            io::Notification::CharacterNonexistentPenalty { .. }
+            | io::Notification::BoosterNonexistentPenalty { .. }
+            | io::Notification::MoveNonexistentPenalty { .. } => self.nonexistent += 1,
+            io::Notification::CharacterThreeTimesInARowPenalty { .. }
+            | io::Notification::MoveThreeTimesInARowPenalty { .. } => self.three_times_in_a_row += 1,
+            io::Notification::BoosterFromWrongCharacterPenalty { .. }
+            | io::Notification::MoveFromWrongCharacterPenalty { .. } => self.wrong_character += 1,
+            io::Notification::MoveFromWrongBoosterPenalty { .. } => self.wrong_booster += 1,
+            io::Notification::MoveSingleUsePenalty { .. }
+            | io::Notification::MoveDestroyedPenalty { .. } => self.destroyed += 1,
+            _ => {},
+        }
+    }
+}
+
+impl AddAssign for PenaltyCounts {
+    fn add_assign(&mut self, other: PenaltyCounts) {
+        self.nonexistent += other.nonexistent;
+        self.three_times_in_a_row += other.three_times_in_a_row;
+        self.wrong_character += other.wrong_character;
+        self.wrong_booster += other.wrong_booster;
+        self.destroyed += other.destroyed;
+    }
+}
+
+/// What happened in one simulated game.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GameSummary {
+    pub seed: u32,
+    /// `None` if the game was abandoned (see `Simulation::max_answers`).
+    pub winner: Option<Side>,
+    pub a_points: u8,
+    pub b_points: u8,
+    /// Rounds in which both players chose a move legally.
+    pub rounds: u32,
+    /// Pairs of answers, including penalized ones.
+    pub answers: u32,
+    /// Who got a headstart for their character, if anyone.
+    pub headstart: Option<Side>,
+    pub a_penalties: PenaltyCounts,
+    pub b_penalties: PenaltyCounts,
+}
+
+/// Settings for playing two strategies against each other.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Simulation {
+    pub rules: Rules,
+    pub games: u32,
+    /// Game `i` is seeded with `first_seed + i` (skipping zero, which the generator can't use).
+    pub first_seed: u32,
+    /// Games that take more answers than this are abandoned.
+    /// Without a limit, a game where neither player has any moves left would never end.
+    pub max_answers: u32,
+}
+
+impl Default for Simulation {
+    fn default() -> Simulation {
+        Simulation {
+            rules: Rules::standard(),
+            games: 1000,
+            first_seed: 1,
+            max_answers: 1000,
+        }
+    }
+}
+
+impl Simulation {
+    /// Plays `self.games` games between the strategies made by `new_a` and `new_b`.
+    ///
+    /// Each game gets new strategies, so strategies that learn don't carry anything over between games.
+    pub fn run<A, B>(&self, mut new_a: A, mut new_b: B) -> SimulationReport
+        where A: FnMut() -> Box<dyn ComputerStrategy>,
+              B: FnMut() -> Box<dyn ComputerStrategy>
+    {
+        let games = (0..self.games)
+            .map(|i| {
+                let seed = match self.first_seed.wrapping_add(i) {
+                    0 => 1,
+                    seed => seed,
+                };
+
+                play_game(self.rules, seed, &mut *new_a(), &mut *new_b(), self.max_answers)
+            })
+            .collect();
+
+        SimulationReport {
+            games,
+        }
+    }
+}
+
+/// Plays one game between `a` and `b`, giving up after `max_answers` pairs of answers.
+///
+/// Both strategies share a random number generator seeded with `seed`.
+pub fn play_game(
+    rules: Rules,
+    seed: u32,
+    a: &mut dyn ComputerStrategy,
+    b: &mut dyn ComputerStrategy,
+    max_answers: u32,
+) -> GameSummary {
+    let mut game = TwoPlayerNZSCGame::new(rules);
+    let mut prfg = PseudorandomFloatGenerator::new(seed);
+    // Each strategy's history, with its opponent as the human.
+    let mut a_history = vec![];
+    let mut b_history = vec![];
+    let mut summary = GameSummary {
+        seed,
+        winner: None,
+        a_points: 0,
+        b_points: 0,
+        rounds: 0,
+        answers: 0,
+        headstart: None,
+        a_penalties: PenaltyCounts::default(),
+        b_penalties: PenaltyCounts::default(),
+    };
+
+    loop {
+        let (a_answer, b_answer) = match game.phase {
+            Phase::CharacterChoosing { a: ref a_player, b: ref b_player } => (
+                io::Answer::CharacterSelection(io::CharacterSelection::Character(strategies::choose_legal_character(a, a_player, b_player, &rules, &a_history, &mut prfg))),
+                io::Answer::CharacterSelection(io::CharacterSelection::Character(strategies::choose_legal_character(b, b_player, a_player, &rules, &b_history, &mut prfg))),
+            ),
+            Phase::BoosterChoosing { a: ref a_player, b: ref b_player } => (
+                io::Answer::BoosterSelection(io::BoosterSelection::Booster(strategies::choose_legal_booster(a, a_player, b_player, &rules, &a_history, &mut prfg))),
+                io::Answer::BoosterSelection(io::BoosterSelection::Booster(strategies::choose_legal_booster(b, b_player, a_player, &rules, &b_history, &mut prfg))),
+            ),
+            Phase::MoveChoosing { a: ref a_player, b: ref b_player } => {
+                summary.a_points = a_player.points;
+                summary.b_points = b_player.points;
+
+                (
+                    io::Answer::MoveSelection(choose_move(a, a_player, b_player, &rules, &a_history, &mut prfg)),
+                    io::Answer::MoveSelection(choose_move(b, b_player, a_player, &rules, &b_history, &mut prfg)),
+                )
+            },
+            Phase::GameOver { a_points, b_points } => {
+                summary.a_points = a_points;
+                summary.b_points = b_points;
+                summary.winner = if a_points > b_points {
+                    Some(Side::A)
+                } else {
+                    Some(Side::B)
+                };
+
+                return summary;
+            },
+        };
+
+        if summary.answers >= max_answers {
+            return summary;
+        }
+
+        let output = game.next(a_answer.clone(), b_answer.clone())
+            .expect("Strategies always answer the current question");
+        summary.answers += 1;
+
+        a_history.push(HistoryEntry {
+            answer: b_answer,
+            computer_choice: ComputerChoice::from_notifications(&output.b.notifications),
+            notifications: output.b.notifications.clone(),
+        });
+        b_history.push(HistoryEntry {
+            answer: a_answer,
+            computer_choice: ComputerChoice::from_notifications(&output.a.notifications),
+            notifications: output.a.notifications.clone(),
+        });
+
+        // `a`'s notifications describe everything, with `a` as the human.
+        for notification in output.a.notifications {
+            match notification {
+                io::Notification::CharacterSelectionAndHeadstart { human_character, computer_character, who_gets_the_headstart } => {
+                    a.observe_character(computer_character);
+                    b.observe_character(human_character);
+                    summary.headstart = match who_gets_the_headstart {
+                        io::WhoGetsTheHeadstart::JustHuman => Some(Side::A),
+                        io::WhoGetsTheHeadstart::JustComputer => Some(Side::B),
+                        io::WhoGetsTheHeadstart::Neither => None,
+                    };
+                },
+                io::Notification::SameCharacterSelection { both_character } => {
+                    a.observe_character(both_character);
+                    b.observe_character(both_character);
+                },
+                io::Notification::BoosterSelection { human_booster, computer_booster } => {
+                    a.observe_booster(computer_booster);
+                    b.observe_booster(human_booster);
+                },
+                io::Notification::MoveSelectionAndOutcome { human_move, computer_move, .. } => {
+                    a.observe_move(computer_move);
+                    b.observe_move(human_move);
+                    summary.rounds += 1;
+                },
+                io::Notification::OpponentPenalty { ref penalty } => summary.b_penalties.record(penalty),
+                ref other => summary.a_penalties.record(other),
+            }
+        }
+    }
+}
+
+/// A player with no moves left (which the rules don't account for) can't choose legally,
+/// so they're penalized as if they chose a nonexistent move.
+fn choose_move(
+    strategy: &mut dyn ComputerStrategy,
+    player: &Player,
+    opponent: &Player,
+    rules: &Rules,
+    history: &[HistoryEntry],
+    prfg: &mut PseudorandomFloatGenerator,
+) -> io::MoveSelection {
+    if player.available_moves_with_rules(rules).is_empty() {
+        io::MoveSelection::Nonexistent(String::new())
+    } else {
+        io::MoveSelection::Move(strategies::choose_legal_move(strategy, player, opponent, rules, history, prfg))
+    }
+}
+
+/// The results of a `Simulation`.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SimulationReport {
+    pub games: Vec<GameSummary>,
+}
+
+impl SimulationReport {
+    pub fn wins(&self, side: Side) -> usize {
+        self.games.iter()
+            .filter(|game| game.winner == Some(side))
+            .count()
+    }
+
+    /// The fraction of all games (including unfinished ones) that `side` won.
+    pub fn win_rate(&self, side: Side) -> f64 {
+        fraction(self.wins(side), self.games.len())
+    }
+
+    /// Games abandoned after `Simulation::max_answers` answers.
+    pub fn unfinished(&self) -> usize {
+        self.games.iter()
+            .filter(|game| game.winner.is_none())
+            .count()
+    }
+
+    /// The average number of rounds in finished games.
+    pub fn average_rounds(&self) -> f64 {
+        let finished: Vec<&GameSummary> = self.games.iter()
+            .filter(|game| game.winner.is_some())
+            .collect();
+        let rounds: u32 = finished.iter().map(|game| game.rounds).sum();
+
+        fraction(rounds as usize, finished.len())
+    }
+
+    /// Finished games in which someone got a headstart.
+    pub fn headstart_games(&self) -> usize {
+        self.games.iter()
+            .filter(|game| game.headstart.is_some() && game.winner.is_some())
+            .count()
+    }
+
+    /// The fraction of `headstart_games` won by the player who got the headstart.
+    pub fn headstart_win_rate(&self) -> f64 {
+        let won = self.games.iter()
+            .filter(|game| game.headstart.is_some() && game.winner == game.headstart)
+            .count();
+
+        fraction(won, self.headstart_games())
+    }
+
+    /// The penalties `side` got in all games.
+    pub fn penalties(&self, side: Side) -> PenaltyCounts {
+        let mut total = PenaltyCounts::default();
+
+        for game in &self.games {
+            total += match side {
+                Side::A => game.a_penalties,
+                Side::B => game.b_penalties,
+            };
+        }
+
+        total
+    }
+}
+
+impl fmt::Display for SimulationReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Games: {} ({} unfinished)", self.games.len(), self.unfinished())?;
+        writeln!(f, "A wins: {} ({:.1}%)", self.wins(Side::A), 100.0 * self.win_rate(Side::A))?;
+        writeln!(f, "B wins: {} ({:.1}%)", self.wins(Side::B), 100.0 * self.win_rate(Side::B))?;
+        writeln!(f, "Average length: {:.2} rounds", self.average_rounds())?;
+        writeln!(
+            f,
+            "Headstarts: {} games, {:.1}% won by the player with the headstart",
+            self.headstart_games(),
+            100.0 * self.headstart_win_rate()
+        )?;
+
+        for &side in &[Side::A, Side::B] {
+            let penalties = self.penalties(side);
+
+            writeln!(
+                f,
+                "{:?} penalties: {} (nonexistent {}, three times in a row {}, wrong character {}, wrong booster {}, destroyed {})",
+                side,
+                penalties.total(),
+                penalties.nonexistent,
+                penalties.three_times_in_a_row,
+                penalties.wrong_character,
+                penalties.wrong_booster,
+                penalties.destroyed
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+fn fraction(count: usize, total: usize) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        count as f64 / total as f64
+    }
+}
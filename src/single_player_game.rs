@@ -1,21 +1,46 @@
+use std::collections::VecDeque;
+
 use super::players::{
+    self,
     CharacterlessPlayer,
     BoosterlessPlayer,
     Player,
 };
 use super::io;
 use super::prfg;
-
-use super::moves::{
-    Move,
-    SINGLE_USE_MOVES,
-    DESTRUCTIVE_MOVES,
-};
+use super::characters::Character;
 use super::boosters::Booster;
+use super::moves::Move;
+use super::streaks::{
+    CharacterStreak,
+    MoveStreak,
+};
+
+use super::penalties;
 use super::outcomes;
+use super::rules::Rules;
+use super::errors::{
+    AnswerError,
+    AnswerType,
+    UndoError,
+};
+use super::history::{
+    ComputerChoice,
+    HistoryEntry,
+};
+use super::strategies::{
+    self,
+    ComputerStrategy,
+    RandomStrategy,
+    SavedStrategy,
+};
+
+/// How many answers in a row can be undone. Older answers are forgotten.
+pub const UNDO_LIMIT: usize = 100;
 
 /// A phase of the game.
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Phase {
     CharacterChoosing {
         human: CharacterlessPlayer,
@@ -35,65 +60,346 @@ pub enum Phase {
     },
 }
 
+impl Phase {
+    /// The type of answer this phase needs, or `None` if the game is over.
+    pub fn expected_answer_type(&self) -> Option<AnswerType> {
+        match *self {
+            Phase::CharacterChoosing { .. } => Some(AnswerType::CharacterSelection),
+            Phase::BoosterChoosing { .. } => Some(AnswerType::BoosterSelection),
+            Phase::MoveChoosing { .. } => Some(AnswerType::MoveSelection),
+            Phase::GameOver { .. } => None,
+        }
+    }
+}
+
+/// One of the players in a single-player game.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Whose {
+    Human,
+    Computer,
+}
+
+/// Games saved before their strategy, seed, rules and history were saved
+/// load with `RandomStrategy`, a seed of 0, the standard rules and an empty history.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SinglePlayerNZSCGame {
     prfg: prfg::PseudorandomFloatGenerator,
+    /// Saved as a `SavedStrategy`, so only games with a strategy that can be saved can be serialized.
+    #[cfg_attr(feature = "serde", serde(
+        default = "default_strategy",
+        serialize_with = "save_strategy",
+        deserialize_with = "restore_strategy"
+    ))]
+    strategy: Box<dyn ComputerStrategy>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    seed: u32,
+    #[cfg_attr(feature = "serde", serde(default))]
+    rules: Rules,
+    #[cfg_attr(feature = "serde", serde(default))]
+    history: Vec<HistoryEntry>,
+    /// The state before each of the last `UNDO_LIMIT` accepted answers, for `undo`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    undo_stack: VecDeque<Snapshot>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    is_undo_disabled: bool,
     pub phase: Phase,
 }
 
+/// What `undo` restores.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+struct Snapshot {
+    phase: Phase,
+    prfg: prfg::PseudorandomFloatGenerator,
+    /// `None` if the strategy can't be saved.
+    #[cfg_attr(feature = "serde", serde(default))]
+    strategy: Option<SavedStrategy>,
+}
+
 impl SinglePlayerNZSCGame {
-    pub fn new(seed: u32) -> SinglePlayerNZSCGame {
+    /// Creates a game against a computer that chooses randomly.
+    ///
+    /// For the standard game, use `Rules::standard()`.
+    pub fn new(seed: u32, rules: Rules) -> SinglePlayerNZSCGame {
+        SinglePlayerNZSCGame::with_strategy(seed, rules, Box::new(RandomStrategy))
+    }
+
+    /// Creates a game against a computer that chooses using `strategy`.
+    pub fn with_strategy(seed: u32, rules: Rules, strategy: Box<dyn ComputerStrategy>) -> SinglePlayerNZSCGame {
         SinglePlayerNZSCGame {
             prfg: prfg::PseudorandomFloatGenerator::new(seed),
+            strategy,
+            seed,
+            rules,
+            history: vec![],
+            undo_stack: VecDeque::new(),
+            is_undo_disabled: false,
             phase: Phase::CharacterChoosing {
-                human: CharacterlessPlayer::new(),
-                computer: CharacterlessPlayer::new(),
+                human: CharacterlessPlayer::with_rules(rules),
+                computer: CharacterlessPlayer::with_rules(rules),
             }
         }
     }
 
-    fn generate_random_index_from_inclusive_max(&mut self, inclusive_max: usize) -> usize {
-        let inclusive_max = inclusive_max as f64;
+    /// Recreates a game against a computer that chooses randomly by answering with `answers`.
+    ///
+    /// Fails if any of the answers can't be accepted.
+    pub fn replay(seed: u32, rules: Rules, answers: Vec<io::Answer>) -> Result<SinglePlayerNZSCGame, AnswerError> {
+        SinglePlayerNZSCGame::replay_with_strategy(seed, rules, Box::new(RandomStrategy), answers)
+    }
+
+    /// Recreates a game against a computer that chooses using `strategy` by answering with `answers`.
+    ///
+    /// `strategy` should be in the same state as the original game's strategy was when the game was created.
+    /// Fails if any of the answers can't be accepted.
+    pub fn replay_with_strategy(seed: u32, rules: Rules, strategy: Box<dyn ComputerStrategy>, answers: Vec<io::Answer>) -> Result<SinglePlayerNZSCGame, AnswerError> {
+        let mut game = SinglePlayerNZSCGame::with_strategy(seed, rules, strategy);
 
-        (self.prfg.next() * (inclusive_max + 1.0)).floor() as usize
+        for answer in answers {
+            game.next(answer)?;
+        }
+
+        Ok(game)
     }
 
-    pub fn initial_output(&self) -> io::Output {
-        if let Phase::CharacterChoosing { ref human, computer: _ }  = self.phase {
-            io::Output {
+    pub fn seed(&self) -> u32 {
+        self.seed
+    }
+
+    pub fn rules(&self) -> Rules {
+        self.rules
+    }
+
+    /// Every accepted answer so far, along with what it led to.
+    pub fn history(&self) -> &[HistoryEntry] {
+        &self.history
+    }
+
+    /// Replaces the computer's strategy (e.g., after deserializing a game).
+    pub fn set_strategy(&mut self, strategy: Box<dyn ComputerStrategy>) {
+        self.strategy = strategy;
+    }
+
+    /// The question currently being asked.
+    ///
+    /// If the game is over, there is no question, and the only notification is `GameOver`.
+    /// Otherwise, there are no notifications.
+    pub fn current_output(&self) -> io::Output {
+        match self.phase {
+            Phase::CharacterChoosing { ref human, .. } => io::Output {
                 question: Some(io::Question::ChooseCharacter {
-                    available_characters: human.available_characters(),
+                    available_characters: human.available_characters_with_rules(&self.rules),
                 }),
                 notifications: vec![],
-            }
+            },
+            Phase::BoosterChoosing { ref human, .. } => io::Output {
+                question: Some(io::Question::ChooseBooster {
+                    available_boosters: human.available_boosters(),
+                }),
+                notifications: vec![],
+            },
+            Phase::MoveChoosing { ref human, .. } => io::Output {
+                question: Some(io::Question::ChooseMove {
+                    available_moves: human.available_moves_with_rules(&self.rules),
+                }),
+                notifications: vec![],
+            },
+            Phase::GameOver { human_points, computer_points } => io::Output {
+                question: None,
+                notifications: vec![
+                    io::Notification::GameOver {
+                        human_points,
+                        computer_points,
+                    }
+                ],
+            },
+        }
+    }
+
+    /// The question currently being asked, or `None` if the game is over.
+    pub fn current_question(&self) -> Option<io::Question> {
+        self.current_output().question
+    }
+
+    pub fn points(&self, whose: Whose) -> u8 {
+        match self.phase {
+            Phase::CharacterChoosing { ref human, ref computer } => pick(whose, human, computer).points,
+            Phase::BoosterChoosing { ref human, ref computer } => pick(whose, human, computer).points,
+            Phase::MoveChoosing { ref human, ref computer } => pick(whose, human, computer).points,
+            Phase::GameOver { human_points, computer_points } => *pick(whose, &human_points, &computer_points),
+        }
+    }
+
+    /// The waits `whose` has left, or `None` if the game is over.
+    pub fn waits(&self, whose: Whose) -> Option<u8> {
+        match self.phase {
+            Phase::CharacterChoosing { ref human, ref computer } => Some(pick(whose, human, computer).waits),
+            Phase::BoosterChoosing { ref human, ref computer } => Some(pick(whose, human, computer).waits),
+            Phase::MoveChoosing { ref human, ref computer } => Some(pick(whose, human, computer).waits),
+            Phase::GameOver { .. } => None,
+        }
+    }
+
+    /// `whose` character, or `None` if characters are still being chosen or the game is over.
+    pub fn character(&self, whose: Whose) -> Option<Character> {
+        match self.phase {
+            Phase::BoosterChoosing { ref human, ref computer } => Some(pick(whose, human, computer).character),
+            Phase::MoveChoosing { ref human, ref computer } => Some(pick(whose, human, computer).character),
+            _ => None,
+        }
+    }
+
+    /// `whose` booster, or `None` unless moves are being chosen.
+    pub fn booster(&self, whose: Whose) -> Option<Booster> {
+        match self.phase {
+            Phase::MoveChoosing { ref human, ref computer } => Some(pick(whose, human, computer).booster),
+            _ => None,
+        }
+    }
+
+    /// The moves `whose` can't choose anymore (including used single-use moves).
+    /// Empty unless moves are being chosen.
+    pub fn destroyed_moves(&self, whose: Whose) -> &[Move] {
+        match self.phase {
+            Phase::MoveChoosing { ref human, ref computer } => &pick(whose, human, computer).destroyed_moves,
+            _ => &[],
+        }
+    }
+
+    /// `None` unless characters are being chosen.
+    pub fn character_streak(&self, whose: Whose) -> Option<CharacterStreak> {
+        match self.phase {
+            Phase::CharacterChoosing { ref human, ref computer } => Some(pick(whose, human, computer).character_streak),
+            _ => None,
+        }
+    }
+
+    /// `None` unless moves are being chosen.
+    pub fn move_streak(&self, whose: Whose) -> Option<MoveStreak> {
+        match self.phase {
+            Phase::MoveChoosing { ref human, ref computer } => Some(pick(whose, human, computer).move_streak),
+            _ => None,
+        }
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.winner().is_some()
+    }
+
+    /// Whoever won, or `None` if the game isn't over.
+    pub fn winner(&self) -> Option<Whose> {
+        match self.phase {
+            Phase::GameOver { human_points, computer_points } => if human_points > computer_points {
+                Some(Whose::Human)
+            } else {
+                Some(Whose::Computer)
+            },
+            _ => None,
+        }
+    }
+
+    /// Takes back the last accepted answer, restoring everything it changed
+    /// (including points, waits, streaks, destroyed moves, the random number generator, and the strategy),
+    /// and returns the current output for the restored question.
+    ///
+    /// Up to `UNDO_LIMIT` answers can be undone one after another.
+    /// Answers can't be undone if the strategy can't be saved (see `ComputerStrategy::save`),
+    /// since strategies that learn would remember the undone answers.
+    /// A restored `AdaptiveStrategy` no longer shares its model with other strategies.
+    pub fn undo(&mut self) -> Result<io::Output, UndoError> {
+        if self.is_undo_disabled {
+            return Err(UndoError::Disabled);
+        }
+        let strategy = match self.undo_stack.back() {
+            Some(&Snapshot { strategy: Some(ref strategy), .. }) => strategy.clone(),
+            Some(&Snapshot { strategy: None, .. }) => return Err(UndoError::UnsavableStrategy),
+            None => return Err(UndoError::NothingToUndo),
+        };
+        let snapshot = self.undo_stack.pop_back().expect("The stack was just checked");
+
+        self.phase = snapshot.phase;
+        self.prfg = snapshot.prfg;
+        self.strategy = strategy.restore();
+        self.history.pop();
+
+        Ok(self.current_output())
+    }
+
+    pub fn can_undo(&self) -> bool {
+        match self.undo_stack.back() {
+            Some(snapshot) => !self.is_undo_disabled && snapshot.strategy.is_some(),
+            None => false,
+        }
+    }
+
+    /// Prevents answers from being undone for the rest of the game (e.g., for ranked play).
+    ///
+    /// This can't be reversed, and answers that were already accepted can't be undone either.
+    pub fn disable_undo(&mut self) {
+        self.is_undo_disabled = true;
+        self.undo_stack.clear();
+    }
+
+    #[deprecated(note = "use `current_output`, which works in every phase")]
+    pub fn initial_output(&self) -> io::Output {
+        self.current_output()
+    }
+
+    /// Fails without changing the game if `answer` doesn't answer the current question.
+    pub fn next(&mut self, answer: io::Answer) -> Result<io::Output, AnswerError> {
+        let snapshot = if self.is_undo_disabled {
+            None
         } else {
-            panic!("Initial output called at wrong phase!");
+            Some(Snapshot {
+                phase: self.phase.clone(),
+                prfg: self.prfg.clone(),
+                strategy: self.strategy.save(),
+            })
+        };
+        let output = self.advance(answer.clone())?;
+
+        if let Some(snapshot) = snapshot {
+            if self.undo_stack.len() == UNDO_LIMIT {
+                self.undo_stack.pop_front();
+            }
+            self.undo_stack.push_back(snapshot);
         }
+
+        self.history.push(HistoryEntry {
+            answer,
+            computer_choice: ComputerChoice::from_notifications(&output.notifications),
+            notifications: output.notifications.clone(),
+        });
+
+        Ok(output)
     }
 
-    pub fn next(&mut self, answer: io::Answer) -> Result<io::Output, ()> {
+    fn advance(&mut self, answer: io::Answer) -> Result<io::Output, AnswerError> {
+        let rules = self.rules;
+
         match (self.phase.clone(), answer) {
             (
                 Phase::CharacterChoosing { mut human, mut computer },
                 io::Answer::CharacterSelection(character_selection)
             ) => {
                 // Closure for the sake of DRY
-                let penalize_human = |waits, penalty_notification, mut human: CharacterlessPlayer, mut computer: CharacterlessPlayer, slf: &mut SinglePlayerNZSCGame| -> Result<io::Output, ()> {
-                    computer.points += human.penalize_waits(waits);
+                let penalize_human = |waits, penalty_notification, mut human: CharacterlessPlayer, mut computer: CharacterlessPlayer, slf: &mut SinglePlayerNZSCGame| -> Result<io::Output, AnswerError> {
+                    let computer_points = human.penalize_waits(waits);
+                    computer.points += computer_points;
 
                     let mut output = io::Output {
                         question: None,
-                        notifications: vec![
-                            penalty_notification,
-                            io::Notification::ScoreUpdate {
-                                human_points: human.points,
-                                computer_points: computer.points,
-                            }
-                        ],
+                        notifications: penalties::notifications(penalty_notification, computer_points),
                     };
+                    output.notifications.push(io::Notification::ScoreUpdate {
+                        human_points: human.points,
+                        computer_points: computer.points,
+                    });
 
-                    if computer.points < 5 {
+                    if computer.points < rules.points_to_win {
                         output.question = Some(io::Question::ChooseCharacter {
-                            available_characters: human.available_characters(),
+                            available_characters: human.available_characters_with_rules(&rules),
                         });
 
                         slf.phase = Phase::CharacterChoosing {
@@ -117,106 +423,97 @@ impl SinglePlayerNZSCGame {
                     Ok(output)
                 };
 
-                match character_selection {
-                    io::CharacterSelection::Character(selected_human_character) => {
-                        if human.available_characters().contains(&selected_human_character) {
-                            let available_computer_characters = computer.available_characters();
-                            let selected_computer_character = available_computer_characters[
-                                self.generate_random_index_from_inclusive_max(available_computer_characters.len() - 1)
-                            ];
+                match penalties::validate_character(&human, &rules, character_selection) {
+                    Ok(selected_human_character) => {
+                        let selected_computer_character = strategies::choose_legal_character(&mut *self.strategy, &computer, &human, &rules, &self.history, &mut self.prfg);
+                        self.strategy.observe_character(selected_human_character);
+
+                        if selected_human_character == selected_computer_character {
+                            human.character_streak.add(selected_human_character);
+                            computer.character_streak.add(selected_computer_character);
+
+                            let available_human_characters = human.available_characters_with_rules(&rules);
+
+                            self.phase = Phase::CharacterChoosing {
+                                human,
+                                computer,
+                            };
+
+                            Ok(io::Output {
+                                question: Some(
+                                    io::Question::ChooseCharacter { available_characters: available_human_characters }
+                                ),
+                                notifications: vec![
+                                    io::Notification::SameCharacterSelection {
+                                        both_character: selected_human_character,
+                                    }
+                                ],
+                            })
+                        } else {
+                            let headstart = outcomes::get_headstart(selected_human_character, selected_computer_character);
+
+                            human.points += headstart.0;
+                            computer.points += headstart.1;
 
-                            if selected_human_character == selected_computer_character {
-                                human.character_streak.add(selected_human_character);
-                                computer.character_streak.add(selected_computer_character);
+                            let who_gets_the_headstart = match headstart {
+                                outcomes::Headstart(0, 0) => io::WhoGetsTheHeadstart::Neither,
+                                outcomes::Headstart(0, 1) => io::WhoGetsTheHeadstart::JustComputer,
+                                outcomes::Headstart(1, 0) => io::WhoGetsTheHeadstart::JustHuman,
+                                outcomes::Headstart(a, b) => panic!("Illegal headstart: {}-{}!", a, b),
+                            };
 
-                                let available_human_characters = human.available_characters();
+                            let human = human.to_boosterless_player(selected_human_character);
+                            let computer = computer.to_boosterless_player(selected_computer_character);
 
-                                self.phase = Phase::CharacterChoosing {
+                            let human_character = human.character;
+                            let computer_character = computer.character;
+
+
+                            // Human might have incurred penalties before successfully choosing character,
+                            // and either player's headstart might be enough to win.
+                            if human.points < rules.points_to_win && computer.points < rules.points_to_win {
+                                self.phase = Phase::BoosterChoosing {
                                     human,
                                     computer,
                                 };
 
                                 Ok(io::Output {
                                     question: Some(
-                                        io::Question::ChooseCharacter { available_characters: available_human_characters }
+                                        io::Question::ChooseBooster { available_boosters: human_character.get_boosters() }
                                     ),
                                     notifications: vec![
-                                        io::Notification::SameCharacterSelection {
-                                            both_character: selected_human_character,
+                                        io::Notification::CharacterSelectionAndHeadstart {
+                                            human_character,
+                                            computer_character,
+                                            who_gets_the_headstart,
                                         }
                                     ],
                                 })
                             } else {
-                                let headstart = outcomes::get_headstart(selected_human_character, selected_computer_character);
-
-                                human.points += headstart.0;
-                                computer.points += headstart.1;
-
-                                let who_gets_the_headstart = match headstart {
-                                    outcomes::Headstart(0, 0) => io::WhoGetsTheHeadstart::Neither,
-                                    outcomes::Headstart(0, 1) => io::WhoGetsTheHeadstart::JustComputer,
-                                    outcomes::Headstart(1, 0) => io::WhoGetsTheHeadstart::JustHuman,
-                                    outcomes::Headstart(a, b) => panic!("Illegal headstart: {}-{}!", a, b),
+                                self.phase = Phase::GameOver {
+                                    human_points: human.points,
+                                    computer_points: computer.points,
                                 };
 
-                                let human = human.to_boosterless_player(selected_human_character);
-                                let computer = computer.to_boosterless_player(selected_computer_character);
-
-                                let human_character = human.character;
-                                let computer_character = computer.character;
-
-
-                                // Human might have incurred penalties before successfully choosing character.
-                                if computer.points < 5 {
-                                    self.phase = Phase::BoosterChoosing {
-                                        human,
-                                        computer,
-                                    };
-
-                                    Ok(io::Output {
-                                        question: Some(
-                                            io::Question::ChooseBooster { available_boosters: human_character.get_boosters() }
-                                        ),
-                                        notifications: vec![
-                                            io::Notification::CharacterSelectionAndHeadstart {
-                                                human_character: human_character,
-                                                computer_character: computer_character,
-                                                who_gets_the_headstart,
-                                            }
-                                        ],
-                                    })
-                                } else {
-                                    self.phase = Phase::GameOver {
-                                        human_points: human.points,
-                                        computer_points: computer.points,
-                                    };
-
-                                    Ok(io::Output {
-                                        question: None,
-                                        notifications: vec![
-                                            io::Notification::CharacterSelectionAndHeadstart {
-                                                human_character: human_character,
-                                                computer_character: computer_character,
-                                                who_gets_the_headstart,
-                                            },
-                                            io::Notification::GameOver {
-                                                human_points: human.points,
-                                                computer_points: computer.points,
-                                            }
-                                        ],
-                                    })
-                                }
+                                Ok(io::Output {
+                                    question: None,
+                                    notifications: vec![
+                                        io::Notification::CharacterSelectionAndHeadstart {
+                                            human_character,
+                                            computer_character,
+                                            who_gets_the_headstart,
+                                        },
+                                        io::Notification::GameOver {
+                                            human_points: human.points,
+                                            computer_points: computer.points,
+                                        }
+                                    ],
+                                })
                             }
-                        } else {
-                            penalize_human(3, io::Notification::CharacterThreeTimesInARowPenalty {
-                                attempted_character: selected_human_character,
-                            }, human, computer, self)
                         }
                     },
-                    io::CharacterSelection::Nonexistent(attempted_character_name) => {
-                        penalize_human(4, io::Notification::CharacterNonexistentPenalty {
-                            attempted_character_name,
-                        }, human, computer, self)
+                    Err(penalty) => {
+                        penalize_human(penalty.waits, penalty.notification, human, computer, self)
                     },
                 }
             },
@@ -225,21 +522,20 @@ impl SinglePlayerNZSCGame {
                 io::Answer::BoosterSelection(booster_selection)
             ) => {
                 // Closure for the sake of DRY
-                let penalize_human = |waits, penalty_notification, mut human: BoosterlessPlayer, mut computer: BoosterlessPlayer, slf: &mut SinglePlayerNZSCGame| -> Result<io::Output, ()> {
-                    computer.points += human.penalize_waits(waits);
+                let penalize_human = |waits, penalty_notification, mut human: BoosterlessPlayer, mut computer: BoosterlessPlayer, slf: &mut SinglePlayerNZSCGame| -> Result<io::Output, AnswerError> {
+                    let computer_points = human.penalize_waits(waits);
+                    computer.points += computer_points;
 
                     let mut output = io::Output {
                         question: None,
-                        notifications: vec![
-                            penalty_notification,
-                            io::Notification::ScoreUpdate {
-                                human_points: human.points,
-                                computer_points: computer.points,
-                            }
-                        ],
+                        notifications: penalties::notifications(penalty_notification, computer_points),
                     };
+                    output.notifications.push(io::Notification::ScoreUpdate {
+                        human_points: human.points,
+                        computer_points: computer.points,
+                    });
 
-                    if computer.points < 5 {
+                    if computer.points < rules.points_to_win {
                         output.question = Some(io::Question::ChooseBooster {
                             available_boosters: human.available_boosters(),
                         });
@@ -265,43 +561,34 @@ impl SinglePlayerNZSCGame {
                     Ok(output)
                 };
 
-                match booster_selection {
-                    io::BoosterSelection::Booster(selected_human_booster) => {
-                        if human.available_boosters().contains(&selected_human_booster) {
-                            let selected_computer_booster = computer.available_boosters()[
-                                self.generate_random_index_from_inclusive_max(1)
-                            ];
-                            let human = human.to_player(selected_human_booster);
-                            let computer = computer.to_player(selected_computer_booster);
+                match penalties::validate_booster(&human, &rules, booster_selection) {
+                    Ok(selected_human_booster) => {
+                        let selected_computer_booster = strategies::choose_legal_booster(&mut *self.strategy, &computer, &human, &rules, &self.history, &mut self.prfg);
+                        self.strategy.observe_booster(selected_human_booster);
+                        let human = human.to_player(selected_human_booster);
+                        let computer = computer.to_player(selected_computer_booster);
 
-                            let human_booster = human.booster;
-                            let computer_booster = computer.booster;
-                            let available_human_moves = human.available_moves();
+                        let human_booster = human.booster;
+                        let computer_booster = computer.booster;
+                        let available_human_moves = human.available_moves_with_rules(&rules);
 
-                            self.phase = Phase::MoveChoosing {
-                                human,
-                                computer,
-                            };
+                        self.phase = Phase::MoveChoosing {
+                            human,
+                            computer,
+                        };
 
-                            Ok(io::Output {
-                                question: Some(io::Question::ChooseMove { available_moves: available_human_moves }),
-                                notifications: vec![
-                                    io::Notification::BoosterSelection {
-                                        human_booster,
-                                        computer_booster,
-                                    }
-                                ],
-                            })
-                        } else {
-                            penalize_human(3, io::Notification::BoosterFromWrongCharacterPenalty {
-                                attempted_booster: selected_human_booster,
-                            }, human, computer, self)
-                        }
+                        Ok(io::Output {
+                            question: Some(io::Question::ChooseMove { available_moves: available_human_moves }),
+                            notifications: vec![
+                                io::Notification::BoosterSelection {
+                                    human_booster,
+                                    computer_booster,
+                                }
+                            ],
+                        })
                     },
-                    io::BoosterSelection::Nonexistent(attempted_booster_name) => {
-                        penalize_human(4, io::Notification::BoosterNonexistentPenalty {
-                            attempted_booster_name,
-                        }, human, computer, self)
+                    Err(penalty) => {
+                        penalize_human(penalty.waits, penalty.notification, human, computer, self)
                     },
                 }
             },
@@ -310,23 +597,22 @@ impl SinglePlayerNZSCGame {
                 io::Answer::MoveSelection(move_selection)
             ) => {
                 // Closure for the sake of DRY
-                let penalize_human = |waits, penalty_notification, mut human: Player, mut computer: Player, slf: &mut SinglePlayerNZSCGame| -> Result<io::Output, ()> {
-                    computer.points += human.penalize_waits(waits);
+                let penalize_human = |waits, penalty_notification, mut human: Player, mut computer: Player, slf: &mut SinglePlayerNZSCGame| -> Result<io::Output, AnswerError> {
+                    let computer_points = human.penalize_waits(waits);
+                    computer.points += computer_points;
 
                     let mut output = io::Output {
                         question: None,
-                        notifications: vec![
-                            penalty_notification,
-                            io::Notification::ScoreUpdate {
-                                human_points: human.points,
-                                computer_points: computer.points,
-                            }
-                        ],
+                        notifications: penalties::notifications(penalty_notification, computer_points),
                     };
+                    output.notifications.push(io::Notification::ScoreUpdate {
+                        human_points: human.points,
+                        computer_points: computer.points,
+                    });
 
-                    if computer.points < 5 {
+                    if computer.points < rules.points_to_win {
                         output.question = Some(io::Question::ChooseMove {
-                            available_moves: human.available_moves(),
+                            available_moves: human.available_moves_with_rules(&rules),
                         });
 
                         slf.phase = Phase::MoveChoosing {
@@ -350,161 +636,116 @@ impl SinglePlayerNZSCGame {
                     Ok(output)
                 };
 
-                match move_selection {
-                    io::MoveSelection::Move(selected_human_move) => {
-                        if human.available_moves().contains(&selected_human_move) {
-                            let available_computer_moves = computer.available_moves();
-                            let selected_computer_move = available_computer_moves[
-                                self.generate_random_index_from_inclusive_max(available_computer_moves.len() - 1)
-                            ];
-
-                            human.move_streak.add(selected_human_move);
-                            computer.move_streak.add(selected_computer_move);
-
-                            if SINGLE_USE_MOVES.contains(&selected_human_move)
-                                || DESTRUCTIVE_MOVES.contains(&selected_computer_move)
-                            {
-                                human.destroyed_moves.push(selected_human_move);
-                            }
-                            if SINGLE_USE_MOVES.contains(&selected_computer_move)
-                                || DESTRUCTIVE_MOVES.contains(&selected_human_move)
-                            {
-                                computer.destroyed_moves.push(selected_computer_move);
-                            }
+                match penalties::validate_move(&human, &rules, move_selection) {
+                    Ok(selected_human_move) => {
+                        let selected_computer_move = strategies::choose_legal_move(&mut *self.strategy, &computer, &human, &rules, &self.history, &mut self.prfg);
+                        self.strategy.observe_move(selected_human_move);
 
-                            let mut points = outcomes::get_points(vec![selected_human_move, selected_computer_move]);
+                        let points = players::play_moves(&mut human, selected_human_move, &mut computer, selected_computer_move);
 
-                            if selected_human_move == Move::ShadowFireball && selected_computer_move == Move::Smash {
-                                if computer.booster == Booster::Strong {
-                                    points[0] = 0;
-                                    points[1] = 1;
-                                } else {
-                                    points[0] = 1;
-                                    points[1] = 0;
-                                }
-                            } else if selected_human_move == Move::Smash && selected_computer_move == Move::ShadowFireball {
-                                if human.booster == Booster::Strong {
-                                    points[0] = 1;
-                                    points[1] = 0;
-                                } else {
-                                    points[0] = 0;
-                                    points[1] = 1;
-                                }
-                            }
+                        let who_gets_the_point = match points {
+                            (0, 0) => io::WhoGetsThePoint::Neither,
+                            (0, 1) => io::WhoGetsThePoint::JustComputer,
+                            (1, 0) => io::WhoGetsThePoint::JustHuman,
+                            (1, 1) => io::WhoGetsThePoint::Both,
+                            (a, b) => panic!("Illegal outcome: {}-{}!", a, b),
+                        };
 
-                            human.points += points[0];
-                            computer.points += points[1];
+                        let mut output = io::Output {
+                            question: None,
+                            notifications: vec![
+                                io::Notification::MoveSelectionAndOutcome {
+                                    human_move: selected_human_move,
+                                    computer_move: selected_computer_move,
+                                    who_gets_the_point,
+                                },
+                                io::Notification::ScoreUpdate {
+                                    human_points: human.points,
+                                    computer_points: computer.points,
+                                }
+                            ],
+                        };
 
-                            let who_gets_the_point = match (points[0], points[1]) {
-                                (0, 0) => io::WhoGetsThePoint::Neither,
-                                (0, 1) => io::WhoGetsThePoint::JustComputer,
-                                (1, 0) => io::WhoGetsThePoint::JustHuman,
-                                (1, 1) => io::WhoGetsThePoint::Both,
-                                (a, b) => panic!("Illegal outcome: {}-{}!", a, b),
-                            };
+                        if human.points >= rules.points_to_win || computer.points >= rules.points_to_win {
+                            if human.points == computer.points {
+                                output.question = Some(io::Question::ChooseMove {
+                                    available_moves: human.available_moves_with_rules(&rules),
+                                });
 
-                            let mut output = io::Output {
-                                question: None,
-                                notifications: vec![
-                                    io::Notification::MoveSelectionAndOutcome {
-                                        human_move: selected_human_move,
-                                        computer_move: selected_computer_move,
-                                        who_gets_the_point,
-                                    },
-                                    io::Notification::ScoreUpdate {
-                                        human_points: human.points,
-                                        computer_points: computer.points,
+                                output.notifications.push(
+                                    io::Notification::TiebreakingScoreSetback {
+                                        both_points: human.points,
                                     }
-                                ],
-                            };
-
-                            if human.points >= 5 || computer.points >= 5 {
-                                if human.points == computer.points {
-                                    output.question = Some(io::Question::ChooseMove {
-                                        available_moves: human.available_moves(),
-                                    });
-
-                                    output.notifications.push(
-                                        io::Notification::TiebreakingScoreSetback {
-                                            both_points: human.points,
-                                        }
-                                    );
-
-                                    human.points = 4;
-                                    computer.points = 4;
+                                );
 
-                                    self.phase = Phase::MoveChoosing {
-                                        human,
-                                        computer,
-                                    };
-                                } else {
-                                    output.notifications.push(
-                                        io::Notification::GameOver {
-                                            human_points: human.points,
-                                            computer_points: computer.points,
-                                        }
-                                    );
-
-                                    self.phase = Phase::GameOver {
-                                        human_points: human.points,
-                                        computer_points: computer.points,
-                                    };
-                                }
-                            } else {
-                                output.question = Some(io::Question::ChooseMove {
-                                    available_moves: human.available_moves(),
-                                });
+                                human.points = rules.tiebreak_points;
+                                computer.points = rules.tiebreak_points;
 
                                 self.phase = Phase::MoveChoosing {
                                     human,
                                     computer,
                                 };
-                            }
-
-                            Ok(output)
-                        } else {
-                            if human.destroyed_moves.contains(&selected_human_move) {
-                                if SINGLE_USE_MOVES.contains(&selected_human_move) {
-                                    penalize_human(4, io::Notification::MoveSingleUsePenalty {
-                                        attempted_move: selected_human_move,
-                                    }, human, computer, self)
-                                } else {
-                                    penalize_human(4, io::Notification::MoveDestroyedPenalty {
-                                        attempted_move: selected_human_move,
-                                    }, human, computer, self)
-                                }
-                            } else if human.move_streak.times == 3 && human.move_streak.repeated_move == Some(selected_human_move) {
-                                penalize_human(3, io::Notification::MoveThreeTimesInARowPenalty {
-                                    attempted_move: selected_human_move,
-                                }, human, computer, self)
                             } else {
-                                let mut booster_moves = vec![];
-                                for booster in &human.character.get_boosters() {
-                                    booster_moves.extend(booster.get_moves());
-                                }
+                                output.notifications.push(
+                                    io::Notification::GameOver {
+                                        human_points: human.points,
+                                        computer_points: computer.points,
+                                    }
+                                );
 
-                                if booster_moves.contains(&selected_human_move) {
-                                    penalize_human(2, io::Notification::MoveFromWrongBoosterPenalty {
-                                        attempted_move: selected_human_move,
-                                    }, human, computer, self)
-                                } else {
-                                    penalize_human(3, io::Notification::MoveFromWrongCharacterPenalty {
-                                        attempted_move: selected_human_move,
-                                    }, human, computer, self)
-                                }
+                                self.phase = Phase::GameOver {
+                                    human_points: human.points,
+                                    computer_points: computer.points,
+                                };
                             }
+                        } else {
+                            output.question = Some(io::Question::ChooseMove {
+                                available_moves: human.available_moves_with_rules(&rules),
+                            });
+
+                            self.phase = Phase::MoveChoosing {
+                                human,
+                                computer,
+                            };
                         }
+
+                        Ok(output)
                     },
-                    io::MoveSelection::Nonexistent(attempted_move_name) => {
-                        penalize_human(4, io::Notification::MoveNonexistentPenalty {
-                            attempted_move_name,
-                        }, human, computer, self)
+                    Err(penalty) => {
+                        penalize_human(penalty.waits, penalty.notification, human, computer, self)
                     },
                 }
             },
-            _ => {
-                Err(())
+            (_, answer) => {
+                Err(AnswerError::unexpected(self.phase.expected_answer_type(), &answer))
             },
         }
     }
 }
+
+fn pick<'a, T>(whose: Whose, human: &'a T, computer: &'a T) -> &'a T {
+    match whose {
+        Whose::Human => human,
+        Whose::Computer => computer,
+    }
+}
+
+#[cfg(feature = "serde")]
+fn default_strategy() -> Box<dyn ComputerStrategy> {
+    Box::new(RandomStrategy)
+}
+
+// `serialize_with` passes a reference to the field itself.
+#[cfg(feature = "serde")]
+#[allow(clippy::borrowed_box)]
+fn save_strategy<S: ::serde::Serializer>(strategy: &Box<dyn ComputerStrategy>, serializer: S) -> Result<S::Ok, S::Error> {
+    match strategy.save() {
+        Some(saved) => ::serde::Serialize::serialize(&saved, serializer),
+        None => Err(::serde::ser::Error::custom("the computer's strategy can't be saved")),
+    }
+}
+
+#[cfg(feature = "serde")]
+fn restore_strategy<'de, D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Box<dyn ComputerStrategy>, D::Error> {
+    <strategies::SavedStrategy as ::serde::Deserialize>::deserialize(deserializer).map(strategies::SavedStrategy::restore)
+}
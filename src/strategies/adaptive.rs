@@ -0,0 +1,304 @@
+use std::collections::BTreeMap;
+use std::sync::{
+    Arc,
+    Mutex,
+    MutexGuard,
+};
+
+use super::{
+    ComputerStrategy,
+    SavedStrategy,
+    choose_best,
+    sample,
+    NO_CHARACTERS,
+    NO_BOOSTERS,
+    NO_MOVES,
+};
+use super::super::players::{
+    CharacterlessPlayer,
+    BoosterlessPlayer,
+    Player,
+};
+use super::super::characters::Character;
+use super::super::boosters::Booster;
+use super::super::moves::Move;
+use super::super::nash;
+use super::super::outcomes;
+use super::super::prfg::PseudorandomFloatGenerator;
+use super::super::history::HistoryEntry;
+use super::super::rules::Rules;
+
+/// Statistics about what an opponent has chosen.
+///
+/// Moves are tracked both by frequency and by which move followed which (bigrams),
+/// so both favorite moves and habitual sequences can be predicted.
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OpponentModel {
+    #[cfg_attr(feature = "serde", serde(with = "pairs"))]
+    character_counts: BTreeMap<Character, u32>,
+    #[cfg_attr(feature = "serde", serde(with = "pairs"))]
+    booster_counts: BTreeMap<(Character, Booster), u32>,
+    #[cfg_attr(feature = "serde", serde(with = "pairs"))]
+    move_counts: BTreeMap<Move, u32>,
+    #[cfg_attr(feature = "serde", serde(with = "pairs"))]
+    move_bigram_counts: BTreeMap<(Move, Move), u32>,
+    last_character: Option<Character>,
+    last_move: Option<Move>,
+}
+
+impl OpponentModel {
+    pub fn new() -> OpponentModel {
+        OpponentModel::default()
+    }
+
+    pub fn record_character(&mut self, character: Character) {
+        *self.character_counts.entry(character).or_insert(0) += 1;
+        self.last_character = Some(character);
+    }
+
+    pub fn record_booster(&mut self, booster: Booster) {
+        if let Some(character) = self.last_character {
+            *self.booster_counts.entry((character, booster)).or_insert(0) += 1;
+        }
+        // A new move phase is starting.
+        self.last_move = None;
+    }
+
+    pub fn record_move(&mut self, opponent_move: Move) {
+        *self.move_counts.entry(opponent_move).or_insert(0) += 1;
+        if let Some(last_move) = self.last_move {
+            *self.move_bigram_counts.entry((last_move, opponent_move)).or_insert(0) += 1;
+        }
+        self.last_move = Some(opponent_move);
+    }
+
+    /// Predicts how likely the opponent is to choose each of `characters`.
+    pub fn predict_characters(&self, characters: &[Character]) -> Vec<(Character, f64)> {
+        normalize(characters, |character| count(&self.character_counts, &character))
+    }
+
+    /// Predicts how likely the opponent (playing `character`) is to choose each of `boosters`.
+    pub fn predict_boosters(&self, character: Character, boosters: &[Booster]) -> Vec<(Booster, f64)> {
+        normalize(boosters, |booster| count(&self.booster_counts, &(character, booster)))
+    }
+
+    /// Predicts how likely the opponent is to choose each of `moves`.
+    ///
+    /// If the opponent's last move has been followed by one of `moves` before,
+    /// the prediction is based on what followed it.
+    /// Otherwise, it is based on how often each move was chosen.
+    pub fn predict_moves(&self, moves: &[Move]) -> Vec<(Move, f64)> {
+        if let Some(last_move) = self.last_move {
+            let has_bigrams = moves.iter()
+                .any(|&next_move| count(&self.move_bigram_counts, &(last_move, next_move)) > 0);
+
+            if has_bigrams {
+                return normalize(moves, |next_move| count(&self.move_bigram_counts, &(last_move, next_move)));
+            }
+        }
+
+        normalize(moves, |opponent_move| count(&self.move_counts, &opponent_move))
+    }
+}
+
+/// Predicts what the opponent will choose from their past choices, and counter-picks.
+///
+/// Always counter-picking would be easy to exploit once noticed,
+/// so moves are sometimes chosen like `NashStrategy` instead (see `with_nash_probability`).
+///
+/// The `OpponentModel` is shared, so it can be reused across games (even on other threads)
+/// by passing the same model to each game's strategy.
+pub struct AdaptiveStrategy {
+    model: Arc<Mutex<OpponentModel>>,
+    nash_probability: f64,
+}
+
+/// How often `AdaptiveStrategy` chooses moves like `NashStrategy` by default.
+pub const DEFAULT_NASH_PROBABILITY: f64 = 0.25;
+
+impl AdaptiveStrategy {
+    pub fn new() -> AdaptiveStrategy {
+        AdaptiveStrategy::with_model(Arc::new(Mutex::new(OpponentModel::new())))
+    }
+
+    pub fn with_model(model: Arc<Mutex<OpponentModel>>) -> AdaptiveStrategy {
+        AdaptiveStrategy {
+            model,
+            nash_probability: DEFAULT_NASH_PROBABILITY,
+        }
+    }
+
+    /// Chooses each move like `NashStrategy` with probability `nash_probability` (clamped to between 0 and 1),
+    /// and counter-picks otherwise.
+    pub fn with_nash_probability(self, nash_probability: f64) -> AdaptiveStrategy {
+        AdaptiveStrategy {
+            nash_probability: nash_probability.clamp(0.0, 1.0),
+            ..self
+        }
+    }
+
+    pub fn model(&self) -> Arc<Mutex<OpponentModel>> {
+        Arc::clone(&self.model)
+    }
+
+    fn lock_model(&self) -> MutexGuard<'_, OpponentModel> {
+        // The model is only ever updated by simple counting, so a poisoned model is still usable.
+        self.model.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Default for AdaptiveStrategy {
+    fn default() -> AdaptiveStrategy {
+        AdaptiveStrategy::new()
+    }
+}
+
+impl ComputerStrategy for AdaptiveStrategy {
+    fn choose_character(
+        &mut self,
+        player: &CharacterlessPlayer,
+        opponent: &CharacterlessPlayer,
+        rules: &Rules,
+        _history: &[HistoryEntry],
+        prfg: &mut PseudorandomFloatGenerator,
+    ) -> Character {
+        let prediction = self.lock_model().predict_characters(&opponent.available_characters_with_rules(rules));
+        let evaluations: Vec<(Character, f64)> = player.available_characters_with_rules(rules).into_iter()
+            .map(|character| {
+                let value = prediction.iter()
+                    .map(|&(opponent_character, probability)| {
+                        let headstart = outcomes::get_headstart(character, opponent_character);
+
+                        probability * (headstart.0 as f64 - headstart.1 as f64)
+                    })
+                    .sum();
+
+                (character, value)
+            })
+            .collect();
+
+        choose_best(&evaluations, prfg).expect(NO_CHARACTERS)
+    }
+
+    fn choose_booster(
+        &mut self,
+        player: &BoosterlessPlayer,
+        opponent: &BoosterlessPlayer,
+        rules: &Rules,
+        _history: &[HistoryEntry],
+        prfg: &mut PseudorandomFloatGenerator,
+    ) -> Booster {
+        let prediction = self.lock_model().predict_boosters(opponent.character, &opponent.available_boosters());
+        // Like `RandomStrategy`, never choose `Booster::None`.
+        let evaluations: Vec<(Booster, f64)> = player.available_boosters().into_iter()
+            .filter(|&booster| booster != Booster::None)
+            .map(|booster| {
+                let player = player.to_player(booster);
+                let value = prediction.iter()
+                    .map(|&(opponent_booster, probability)| {
+                        probability * nash::solve_moves(&player, &opponent.to_player(opponent_booster), rules).value
+                    })
+                    .sum();
+
+                (booster, value)
+            })
+            .collect();
+
+        choose_best(&evaluations, prfg).expect(NO_BOOSTERS)
+    }
+
+    fn choose_move(
+        &mut self,
+        player: &Player,
+        opponent: &Player,
+        rules: &Rules,
+        _history: &[HistoryEntry],
+        prfg: &mut PseudorandomFloatGenerator,
+    ) -> Move {
+        if prfg.next() < self.nash_probability {
+            return sample(&nash::solve_moves(player, opponent, rules).player_strategy, prfg).expect(NO_MOVES);
+        }
+
+        let prediction = self.lock_model().predict_moves(&opponent.available_moves_with_rules(rules));
+        let evaluations: Vec<(Move, f64)> = player.available_moves_with_rules(rules).into_iter()
+            .map(|player_move| {
+                let value = prediction.iter()
+                    .map(|&(opponent_move, probability)| {
+                        let points = outcomes::get_move_points(player_move, player.booster, opponent_move, opponent.booster);
+
+                        probability * (points.0 as f64 - points.1 as f64)
+                    })
+                    .sum();
+
+                (player_move, value)
+            })
+            .collect();
+
+        choose_best(&evaluations, prfg).expect(NO_MOVES)
+    }
+
+    fn observe_character(&mut self, character: Character) {
+        self.lock_model().record_character(character);
+    }
+
+    fn observe_booster(&mut self, booster: Booster) {
+        self.lock_model().record_booster(booster);
+    }
+
+    fn observe_move(&mut self, opponent_move: Move) {
+        self.lock_model().record_move(opponent_move);
+    }
+
+    fn save(&self) -> Option<SavedStrategy> {
+        Some(SavedStrategy::Adaptive {
+            model: self.lock_model().clone(),
+            nash_probability: self.nash_probability,
+        })
+    }
+}
+
+fn count<K: Ord>(counts: &BTreeMap<K, u32>, key: &K) -> u32 {
+    *counts.get(key).unwrap_or(&0)
+}
+
+/// Turns counts into probabilities, adding one to every count so unseen options are still possible.
+fn normalize<T: Copy, F: Fn(T) -> u32>(options: &[T], get_count: F) -> Vec<(T, f64)> {
+    let smoothed_counts: Vec<f64> = options.iter()
+        .map(|&option| get_count(option) as f64 + 1.0)
+        .collect();
+    let total: f64 = smoothed_counts.iter().sum();
+
+    options.iter().cloned()
+        .zip(smoothed_counts.into_iter().map(|smoothed_count| smoothed_count / total))
+        .collect()
+}
+
+/// Saves maps as lists of pairs, since some keys are tuples (and JSON keys must be strings).
+#[cfg(feature = "serde")]
+mod pairs {
+    use std::collections::BTreeMap;
+
+    use serde::{
+        Serialize,
+        Serializer,
+        Deserialize,
+        Deserializer,
+    };
+
+    pub fn serialize<K, V, S>(map: &BTreeMap<K, V>, serializer: S) -> Result<S::Ok, S::Error>
+        where K: Serialize,
+              V: Serialize,
+              S: Serializer
+    {
+        serializer.collect_seq(map.iter())
+    }
+
+    pub fn deserialize<'de, K, V, D>(deserializer: D) -> Result<BTreeMap<K, V>, D::Error>
+        where K: Deserialize<'de> + Ord,
+              V: Deserialize<'de>,
+              D: Deserializer<'de>
+    {
+        Vec::<(K, V)>::deserialize(deserializer).map(|pairs| pairs.into_iter().collect())
+    }
+}
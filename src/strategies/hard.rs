@@ -0,0 +1,161 @@
+use super::{
+    ComputerStrategy,
+    SavedStrategy,
+    RandomStrategy,
+    choose_best,
+    NO_BOOSTERS,
+    NO_MOVES,
+};
+use super::super::players::{
+    self,
+    CharacterlessPlayer,
+    BoosterlessPlayer,
+    Player,
+};
+use super::super::characters::Character;
+use super::super::boosters::Booster;
+use super::super::moves::Move;
+use super::super::prfg::PseudorandomFloatGenerator;
+use super::super::history::HistoryEntry;
+use super::super::rules::Rules;
+
+/// Searches ahead over both players' available moves and chooses the move
+/// that maximizes the expected point differential.
+///
+/// The search assumes the opponent chooses uniformly at random (expectimax).
+/// Characters are chosen randomly, since no character has an advantage before the opponent's is known.
+#[derive(Clone, Copy)]
+pub struct HardStrategy {
+    depth: u8,
+}
+
+impl HardStrategy {
+    /// Creates a strategy that searches 3 rounds ahead.
+    pub fn new() -> HardStrategy {
+        HardStrategy::with_depth(3)
+    }
+
+    /// Creates a strategy that searches `depth` rounds ahead.
+    ///
+    /// A `depth` of 0 is treated as 1.
+    pub fn with_depth(depth: u8) -> HardStrategy {
+        HardStrategy {
+            depth: depth.max(1),
+        }
+    }
+}
+
+impl Default for HardStrategy {
+    fn default() -> HardStrategy {
+        HardStrategy::new()
+    }
+}
+
+impl ComputerStrategy for HardStrategy {
+    fn choose_character(
+        &mut self,
+        player: &CharacterlessPlayer,
+        opponent: &CharacterlessPlayer,
+        rules: &Rules,
+        history: &[HistoryEntry],
+        prfg: &mut PseudorandomFloatGenerator,
+    ) -> Character {
+        RandomStrategy.choose_character(player, opponent, rules, history, prfg)
+    }
+
+    fn choose_booster(
+        &mut self,
+        player: &BoosterlessPlayer,
+        opponent: &BoosterlessPlayer,
+        rules: &Rules,
+        _history: &[HistoryEntry],
+        prfg: &mut PseudorandomFloatGenerator,
+    ) -> Booster {
+        let opponent_boosters = opponent.available_boosters();
+        // Like `RandomStrategy`, never choose `Booster::None`.
+        let evaluations: Vec<(Booster, f64)> = player.available_boosters().into_iter()
+            .filter(|&booster| booster != Booster::None)
+            .map(|booster| {
+                let player = player.to_player(booster);
+                let total: f64 = opponent_boosters.iter()
+                    .map(|&opponent_booster| {
+                        search(&player, &opponent.to_player(opponent_booster), rules, 1)
+                    })
+                    .sum();
+
+                (booster, total / opponent_boosters.len() as f64)
+            })
+            .collect();
+
+        choose_best(&evaluations, prfg).expect(NO_BOOSTERS)
+    }
+
+    fn choose_move(
+        &mut self,
+        player: &Player,
+        opponent: &Player,
+        rules: &Rules,
+        _history: &[HistoryEntry],
+        prfg: &mut PseudorandomFloatGenerator,
+    ) -> Move {
+        choose_best(&evaluate_moves(player, opponent, rules, self.depth), prfg).expect(NO_MOVES)
+    }
+
+    fn save(&self) -> Option<SavedStrategy> {
+        Some(SavedStrategy::Hard {
+            depth: self.depth,
+        })
+    }
+}
+
+/// Evaluates each of `player`'s available moves under `rules` by searching `depth` rounds ahead.
+///
+/// Each move is paired with the expected point differential (`player`'s points minus `opponent`'s points)
+/// it leads to, assuming `opponent` chooses uniformly at random and `player` chooses optimally afterwards.
+pub fn evaluate_moves(player: &Player, opponent: &Player, rules: &Rules, depth: u8) -> Vec<(Move, f64)> {
+    player.available_moves_with_rules(rules).into_iter()
+        .map(|player_move| (player_move, evaluate_move(player, opponent, rules, player_move, depth.max(1))))
+        .collect()
+}
+
+fn evaluate_move(player: &Player, opponent: &Player, rules: &Rules, player_move: Move, depth: u8) -> f64 {
+    let opponent_moves = opponent.available_moves_with_rules(rules);
+
+    if opponent_moves.is_empty() {
+        return rules.points_to_win as f64;
+    }
+
+    let total: f64 = opponent_moves.iter()
+        .map(|&opponent_move| {
+            let mut player = player.clone();
+            let mut opponent = opponent.clone();
+            let points = players::play_moves(&mut player, player_move, &mut opponent, opponent_move);
+            let differential = points.0 as f64 - points.1 as f64;
+
+            if player.points >= rules.points_to_win || opponent.points >= rules.points_to_win {
+                if player.points == opponent.points {
+                    player.points = rules.tiebreak_points;
+                    opponent.points = rules.tiebreak_points;
+                } else {
+                    return differential;
+                }
+            }
+
+            differential + search(&player, &opponent, rules, depth - 1)
+        })
+        .sum();
+
+    total / opponent_moves.len() as f64
+}
+
+fn search(player: &Player, opponent: &Player, rules: &Rules, depth: u8) -> f64 {
+    if depth == 0 {
+        return 0.0;
+    }
+
+    // Running out of moves is as bad as losing.
+    player.available_moves_with_rules(rules).into_iter()
+        .map(|player_move| evaluate_move(player, opponent, rules, player_move, depth))
+        .fold(None, |best: Option<f64>, value| Some(best.map_or(value, |best| best.max(value))))
+        .unwrap_or(-(rules.points_to_win as f64))
+}
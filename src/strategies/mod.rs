@@ -0,0 +1,233 @@
+use super::players::{
+    CharacterlessPlayer,
+    BoosterlessPlayer,
+    Player,
+};
+use super::characters::Character;
+use super::boosters::Booster;
+use super::moves::Move;
+use super::prfg::PseudorandomFloatGenerator;
+use super::history::HistoryEntry;
+use super::rules::Rules;
+
+pub use self::random::RandomStrategy;
+pub use self::hard::HardStrategy;
+pub use self::nash::NashStrategy;
+pub use self::adaptive::{
+    AdaptiveStrategy,
+    OpponentModel,
+    DEFAULT_NASH_PROBABILITY,
+};
+pub use self::scripted::{
+    Script,
+    ScriptedStrategy,
+};
+
+mod random;
+pub mod hard;
+mod nash;
+mod adaptive;
+mod scripted;
+
+/// Decides what the computer chooses in each phase of the game.
+///
+/// Every method receives the state of the player the strategy is choosing for (`player`)
+/// and of their opponent (`opponent`), which together make up the current `Phase`,
+/// the game's `rules`, every earlier exchange of the game (`history`), and the game's random number generator.
+/// In `history`, `answer` is what the opponent answered and `computer_choice` is what the strategy chose.
+///
+/// Strategies should only choose from `player`'s available characters, boosters, or moves under `rules`.
+/// Games check this, and choose like `RandomStrategy` instead of an unavailable option.
+///
+/// Strategies must be `Send`, so games can be handed to other threads (e.g., by a server).
+pub trait ComputerStrategy: Send {
+    fn choose_character(
+        &mut self,
+        player: &CharacterlessPlayer,
+        opponent: &CharacterlessPlayer,
+        rules: &Rules,
+        history: &[HistoryEntry],
+        prfg: &mut PseudorandomFloatGenerator,
+    ) -> Character;
+
+    fn choose_booster(
+        &mut self,
+        player: &BoosterlessPlayer,
+        opponent: &BoosterlessPlayer,
+        rules: &Rules,
+        history: &[HistoryEntry],
+        prfg: &mut PseudorandomFloatGenerator,
+    ) -> Booster;
+
+    fn choose_move(
+        &mut self,
+        player: &Player,
+        opponent: &Player,
+        rules: &Rules,
+        history: &[HistoryEntry],
+        prfg: &mut PseudorandomFloatGenerator,
+    ) -> Move;
+
+    /// Called after the opponent (legally) chooses `character`.
+    ///
+    /// This is always called after `choose_character`, so strategies can't cheat.
+    /// Does nothing by default.
+    fn observe_character(&mut self, _character: Character) {}
+
+    /// Called after the opponent (legally) chooses `booster`.
+    ///
+    /// This is always called after `choose_booster`, so strategies can't cheat.
+    /// Does nothing by default.
+    fn observe_booster(&mut self, _booster: Booster) {}
+
+    /// Called after the opponent (legally) chooses `opponent_move`.
+    ///
+    /// This is always called after `choose_move`, so strategies can't cheat.
+    /// Does nothing by default.
+    fn observe_move(&mut self, _opponent_move: Move) {}
+
+    /// Everything needed to recreate the strategy in its current state (see `SavedStrategy`),
+    /// or `None` if it can't be saved.
+    ///
+    /// Games can only be serialized if their strategy can be saved.
+    /// Returns `None` by default.
+    fn save(&self) -> Option<SavedStrategy> {
+        None
+    }
+}
+
+/// A built-in strategy and its state, so it can be saved with a game.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SavedStrategy {
+    Random,
+    Hard {
+        depth: u8,
+    },
+    Nash,
+    /// The model is saved as it is, so it's no longer shared with other strategies once restored.
+    Adaptive {
+        model: OpponentModel,
+        nash_probability: f64,
+    },
+    /// What's left of the script.
+    Scripted(Script),
+}
+
+impl SavedStrategy {
+    /// Recreates the saved strategy.
+    pub fn restore(self) -> Box<dyn ComputerStrategy> {
+        match self {
+            SavedStrategy::Random => Box::new(RandomStrategy),
+            SavedStrategy::Hard { depth } => Box::new(HardStrategy::with_depth(depth)),
+            SavedStrategy::Nash => Box::new(NashStrategy),
+            SavedStrategy::Adaptive { model, nash_probability } => Box::new(
+                AdaptiveStrategy::with_model(::std::sync::Arc::new(::std::sync::Mutex::new(model)))
+                    .with_nash_probability(nash_probability)
+            ),
+            SavedStrategy::Scripted(script) => Box::new(ScriptedStrategy::new(script)),
+        }
+    }
+}
+
+/// Asks `strategy` for a character, choosing randomly instead if it chooses one `player` doesn't have available under `rules`.
+pub fn choose_legal_character(
+    strategy: &mut dyn ComputerStrategy,
+    player: &CharacterlessPlayer,
+    opponent: &CharacterlessPlayer,
+    rules: &Rules,
+    history: &[HistoryEntry],
+    prfg: &mut PseudorandomFloatGenerator,
+) -> Character {
+    let character = strategy.choose_character(player, opponent, rules, history, prfg);
+
+    if player.available_characters_with_rules(rules).contains(&character) {
+        character
+    } else {
+        RandomStrategy.choose_character(player, opponent, rules, history, prfg)
+    }
+}
+
+/// Asks `strategy` for a booster, choosing randomly instead if it chooses one `player` doesn't have available.
+pub fn choose_legal_booster(
+    strategy: &mut dyn ComputerStrategy,
+    player: &BoosterlessPlayer,
+    opponent: &BoosterlessPlayer,
+    rules: &Rules,
+    history: &[HistoryEntry],
+    prfg: &mut PseudorandomFloatGenerator,
+) -> Booster {
+    let booster = strategy.choose_booster(player, opponent, rules, history, prfg);
+
+    if player.available_boosters().contains(&booster) {
+        booster
+    } else {
+        RandomStrategy.choose_booster(player, opponent, rules, history, prfg)
+    }
+}
+
+/// Asks `strategy` for a move, choosing randomly instead if it chooses one `player` doesn't have available under `rules`.
+pub fn choose_legal_move(
+    strategy: &mut dyn ComputerStrategy,
+    player: &Player,
+    opponent: &Player,
+    rules: &Rules,
+    history: &[HistoryEntry],
+    prfg: &mut PseudorandomFloatGenerator,
+) -> Move {
+    let chosen_move = strategy.choose_move(player, opponent, rules, history, prfg);
+
+    if player.available_moves_with_rules(rules).contains(&chosen_move) {
+        chosen_move
+    } else {
+        RandomStrategy.choose_move(player, opponent, rules, history, prfg)
+    }
+}
+
+/// Chooses randomly among the options with the highest value, or returns `None` if there are no options.
+fn choose_best<T: Copy>(evaluations: &[(T, f64)], prfg: &mut PseudorandomFloatGenerator) -> Option<T> {
+    let best_value = evaluations.iter()
+        .map(|&(_, value)| value)
+        .fold(f64::NEG_INFINITY, f64::max);
+    let best: Vec<T> = evaluations.iter()
+        .filter(|&&(_, value)| best_value - value < 1e-9)
+        .map(|&(option, _)| option)
+        .collect();
+
+    if best.is_empty() {
+        None
+    } else {
+        Some(best[prfg.generate_random_index_from_inclusive_max(best.len() - 1)])
+    }
+}
+
+/// Chooses an option with its given (relative) probability, or returns `None` if there are no options.
+///
+/// If every probability is zero, chooses uniformly.
+fn sample<T: Copy>(strategy: &[(T, f64)], prfg: &mut PseudorandomFloatGenerator) -> Option<T> {
+    if strategy.is_empty() {
+        return None;
+    }
+
+    let total: f64 = strategy.iter().map(|&(_, probability)| probability).sum();
+
+    if total <= 0.0 {
+        return Some(strategy[prfg.generate_random_index_from_inclusive_max(strategy.len() - 1)].0);
+    }
+
+    let mut remaining = prfg.next() * total;
+
+    for &(option, probability) in strategy {
+        if remaining < probability {
+            return Some(option);
+        }
+        remaining -= probability;
+    }
+
+    Some(strategy[strategy.len() - 1].0)
+}
+
+const NO_CHARACTERS: &str = "A player always has an available character";
+const NO_BOOSTERS: &str = "Every character has a booster besides `Booster::None`";
+/// The rules don't say what happens when a player has destroyed or used up all of their moves.
+const NO_MOVES: &str = "A strategy was asked to choose a move for a player with no available moves";
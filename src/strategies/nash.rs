@@ -0,0 +1,66 @@
+use super::{
+    ComputerStrategy,
+    SavedStrategy,
+    sample,
+    NO_CHARACTERS,
+    NO_BOOSTERS,
+    NO_MOVES,
+};
+use super::super::players::{
+    CharacterlessPlayer,
+    BoosterlessPlayer,
+    Player,
+};
+use super::super::characters::Character;
+use super::super::boosters::Booster;
+use super::super::moves::Move;
+use super::super::nash;
+use super::super::prfg::PseudorandomFloatGenerator;
+use super::super::history::HistoryEntry;
+use super::super::rules::Rules;
+
+/// Chooses randomly according to the game-theoretically optimal mixed strategy of each phase.
+///
+/// See the `nash` module for how each phase is solved.
+#[derive(Clone, Copy, Default)]
+pub struct NashStrategy;
+
+impl ComputerStrategy for NashStrategy {
+    fn choose_character(
+        &mut self,
+        player: &CharacterlessPlayer,
+        opponent: &CharacterlessPlayer,
+        rules: &Rules,
+        _history: &[HistoryEntry],
+        prfg: &mut PseudorandomFloatGenerator,
+    ) -> Character {
+        sample(&nash::solve_characters(player, opponent, rules).player_strategy, prfg).expect(NO_CHARACTERS)
+    }
+
+    fn choose_booster(
+        &mut self,
+        player: &BoosterlessPlayer,
+        opponent: &BoosterlessPlayer,
+        rules: &Rules,
+        _history: &[HistoryEntry],
+        prfg: &mut PseudorandomFloatGenerator,
+    ) -> Booster {
+        // Like `RandomStrategy`, never choose `Booster::None` (which `solve_boosters` leaves out).
+        sample(&nash::solve_boosters(player, opponent, rules).player_strategy, prfg).expect(NO_BOOSTERS)
+    }
+
+    fn choose_move(
+        &mut self,
+        player: &Player,
+        opponent: &Player,
+        rules: &Rules,
+        _history: &[HistoryEntry],
+        prfg: &mut PseudorandomFloatGenerator,
+    ) -> Move {
+        sample(&nash::solve_moves(player, opponent, rules).player_strategy, prfg).expect(NO_MOVES)
+    }
+
+    fn save(&self) -> Option<SavedStrategy> {
+        Some(SavedStrategy::Nash)
+    }
+}
@@ -0,0 +1,73 @@
+use super::{
+    ComputerStrategy,
+    SavedStrategy,
+    NO_MOVES,
+};
+use super::super::players::{
+    CharacterlessPlayer,
+    BoosterlessPlayer,
+    Player,
+};
+use super::super::characters::Character;
+use super::super::boosters::Booster;
+use super::super::moves::Move;
+use super::super::prfg::PseudorandomFloatGenerator;
+use super::super::history::HistoryEntry;
+use super::super::rules::Rules;
+
+/// Chooses uniformly at random.
+///
+/// This is the default strategy.
+#[derive(Clone, Copy, Default)]
+pub struct RandomStrategy;
+
+impl ComputerStrategy for RandomStrategy {
+    fn choose_character(
+        &mut self,
+        player: &CharacterlessPlayer,
+        _opponent: &CharacterlessPlayer,
+        rules: &Rules,
+        _history: &[HistoryEntry],
+        prfg: &mut PseudorandomFloatGenerator,
+    ) -> Character {
+        let available_characters = player.available_characters_with_rules(rules);
+
+        available_characters[
+            prfg.generate_random_index_from_inclusive_max(available_characters.len() - 1)
+        ]
+    }
+
+    fn choose_booster(
+        &mut self,
+        player: &BoosterlessPlayer,
+        _opponent: &BoosterlessPlayer,
+        _rules: &Rules,
+        _history: &[HistoryEntry],
+        prfg: &mut PseudorandomFloatGenerator,
+    ) -> Booster {
+        // The computer never chooses `Booster::None`, which is always the last booster.
+        player.available_boosters()[
+            prfg.generate_random_index_from_inclusive_max(1)
+        ]
+    }
+
+    fn choose_move(
+        &mut self,
+        player: &Player,
+        _opponent: &Player,
+        rules: &Rules,
+        _history: &[HistoryEntry],
+        prfg: &mut PseudorandomFloatGenerator,
+    ) -> Move {
+        let available_moves = player.available_moves_with_rules(rules);
+        assert!(!available_moves.is_empty(), "{}", NO_MOVES);
+
+        available_moves[
+            prfg.generate_random_index_from_inclusive_max(available_moves.len() - 1)
+        ]
+    }
+
+    fn save(&self) -> Option<SavedStrategy> {
+        Some(SavedStrategy::Random)
+    }
+}
@@ -0,0 +1,100 @@
+use std::collections::VecDeque;
+
+use super::{
+    ComputerStrategy,
+    SavedStrategy,
+    RandomStrategy,
+};
+use super::super::players::{
+    CharacterlessPlayer,
+    BoosterlessPlayer,
+    Player,
+};
+use super::super::characters::Character;
+use super::super::boosters::Booster;
+use super::super::moves::Move;
+use super::super::prfg::PseudorandomFloatGenerator;
+use super::super::history::HistoryEntry;
+use super::super::rules::Rules;
+
+/// What a `ScriptedStrategy` chooses, in order.
+#[derive(Clone, Default, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Script {
+    pub characters: Vec<Character>,
+    pub boosters: Vec<Booster>,
+    pub moves: Vec<Move>,
+}
+
+/// Follows a `Script`, e.g., to set up situations in a tutorial.
+///
+/// Each choice uses up the next scripted option of its kind.
+/// Once a kind runs out, or if the scripted option isn't available, the strategy chooses like `RandomStrategy`.
+#[derive(Clone, Default)]
+pub struct ScriptedStrategy {
+    characters: VecDeque<Character>,
+    boosters: VecDeque<Booster>,
+    moves: VecDeque<Move>,
+}
+
+impl ScriptedStrategy {
+    pub fn new(script: Script) -> ScriptedStrategy {
+        ScriptedStrategy {
+            characters: script.characters.into(),
+            boosters: script.boosters.into(),
+            moves: script.moves.into(),
+        }
+    }
+}
+
+impl ComputerStrategy for ScriptedStrategy {
+    fn choose_character(
+        &mut self,
+        player: &CharacterlessPlayer,
+        opponent: &CharacterlessPlayer,
+        rules: &Rules,
+        history: &[HistoryEntry],
+        prfg: &mut PseudorandomFloatGenerator,
+    ) -> Character {
+        match self.characters.pop_front() {
+            Some(character) if player.available_characters_with_rules(rules).contains(&character) => character,
+            _ => RandomStrategy.choose_character(player, opponent, rules, history, prfg),
+        }
+    }
+
+    fn choose_booster(
+        &mut self,
+        player: &BoosterlessPlayer,
+        opponent: &BoosterlessPlayer,
+        rules: &Rules,
+        history: &[HistoryEntry],
+        prfg: &mut PseudorandomFloatGenerator,
+    ) -> Booster {
+        match self.boosters.pop_front() {
+            Some(booster) if player.available_boosters().contains(&booster) => booster,
+            _ => RandomStrategy.choose_booster(player, opponent, rules, history, prfg),
+        }
+    }
+
+    fn choose_move(
+        &mut self,
+        player: &Player,
+        opponent: &Player,
+        rules: &Rules,
+        history: &[HistoryEntry],
+        prfg: &mut PseudorandomFloatGenerator,
+    ) -> Move {
+        match self.moves.pop_front() {
+            Some(scripted_move) if player.available_moves_with_rules(rules).contains(&scripted_move) => scripted_move,
+            _ => RandomStrategy.choose_move(player, opponent, rules, history, prfg),
+        }
+    }
+
+    fn save(&self) -> Option<SavedStrategy> {
+        Some(SavedStrategy::Scripted(Script {
+            characters: self.characters.iter().cloned().collect(),
+            boosters: self.boosters.iter().cloned().collect(),
+            moves: self.moves.iter().cloned().collect(),
+        }))
+    }
+}
@@ -5,6 +5,7 @@ use super::characters::Character;
 ///
 /// Used for enforcing three-times-in-a-row rule.
 #[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct MoveStreak {
     pub repeated_move: Option<Move>,
     pub times: u8
@@ -14,6 +15,7 @@ pub struct MoveStreak {
 ///
 /// Used for enforcing three-times-in-a-row rule.
 #[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CharacterStreak {
     pub repeated_character: Option<Character>,
     pub times: u8
@@ -43,6 +45,12 @@ impl MoveStreak {
     }
 }
 
+impl Default for MoveStreak {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl CharacterStreak {
     pub fn new() -> Self {
         Self {
@@ -66,3 +74,9 @@ impl CharacterStreak {
         }
     }
 }
+
+impl Default for CharacterStreak {
+    fn default() -> Self {
+        Self::new()
+    }
+}
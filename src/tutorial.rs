@@ -0,0 +1,302 @@
+//! A game for learning the rules, where the computer follows a script
+//! and each answer comes with explanations of what happened.
+
+use std::fmt;
+
+use super::single_player_game::{
+    SinglePlayerNZSCGame,
+    Phase,
+};
+pub use super::single_player_game::Whose;
+use super::strategies::{
+    Script,
+    ScriptedStrategy,
+};
+use super::players::Player;
+use super::rules::Rules;
+use super::characters::Character;
+use super::boosters::Booster;
+use super::moves::{
+    Move,
+    SINGLE_USE_MOVES,
+};
+use super::errors::AnswerError;
+use super::locale::{
+    English,
+    Locale,
+    Message,
+    fill,
+    waits_text,
+};
+use super::io::{
+    self,
+    WhoGetsThePoint,
+    WhoGetsTheHeadstart,
+};
+
+/// Why something in a tutorial happened.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Explanation {
+    /// `winner`'s character gets a headstart against `loser`'s.
+    Headstart {
+        winner: Character,
+        loser: Character,
+    },
+    /// Why `who_gets_the_point` got the point for a round.
+    Point {
+        human_move: Move,
+        computer_move: Move,
+        who_gets_the_point: WhoGetsThePoint,
+    },
+    /// `destroyer` destroyed `destroyed_move`, so its owner can't choose it again.
+    Destroyed {
+        whose: Whose,
+        destroyer: Move,
+        destroyed_move: Move,
+    },
+    /// `used_move` can only be used once.
+    SingleUse {
+        whose: Whose,
+        used_move: Move,
+    },
+    /// `repeated_move` was chosen `times` times in a row, so it can't be chosen next round.
+    MoveStreakLimit {
+        whose: Whose,
+        repeated_move: Move,
+        times: u8,
+    },
+    /// `repeated_character` was chosen `times` times in a row, so it can't be chosen next time.
+    CharacterStreakLimit {
+        whose: Whose,
+        repeated_character: Character,
+        times: u8,
+    },
+    /// How penalties work, given after the human's first penalty. The human has `waits` left.
+    Waits {
+        waits: u8,
+    },
+}
+
+impl Explanation {
+    /// Renders the explanation in `locale`.
+    pub fn render<L: Locale + ?Sized>(&self, locale: &L) -> String {
+        match *self {
+            Explanation::Headstart { winner, loser } => {
+                fill(&locale.message(Message::ExplainHeadstart), &[
+                    ("winner", &locale.character_name(winner)),
+                    ("loser", &locale.character_name(loser)),
+                ])
+            },
+            Explanation::Point { human_move, computer_move, who_gets_the_point } => {
+                let (message, human_key, computer_key) = match who_gets_the_point {
+                    WhoGetsThePoint::JustHuman => (Message::ExplainPoint, "winner", "loser"),
+                    WhoGetsThePoint::JustComputer => (Message::ExplainPoint, "loser", "winner"),
+                    WhoGetsThePoint::Neither => (Message::ExplainNoPoint, "human", "computer"),
+                    WhoGetsThePoint::Both => (Message::ExplainBothPoints, "human", "computer"),
+                };
+
+                fill(&locale.message(message), &[
+                    (human_key, &locale.move_name(human_move)),
+                    (computer_key, &locale.move_name(computer_move)),
+                ])
+            },
+            Explanation::Destroyed { whose, destroyer, destroyed_move } => {
+                let message = match whose {
+                    Whose::Human => Message::ExplainYourMoveDestroyed,
+                    Whose::Computer => Message::ExplainComputerMoveDestroyed,
+                };
+                fill(&locale.message(message), &[
+                    ("destroyer", &locale.move_name(destroyer)),
+                    ("name", &locale.move_name(destroyed_move)),
+                ])
+            },
+            Explanation::SingleUse { whose, used_move } => {
+                let message = match whose {
+                    Whose::Human => Message::ExplainYourSingleUse,
+                    Whose::Computer => Message::ExplainComputerSingleUse,
+                };
+                fill(&locale.message(message), &[("name", &locale.move_name(used_move))])
+            },
+            Explanation::MoveStreakLimit { whose, repeated_move, times } => {
+                streak_limit_text(locale, whose, &locale.move_name(repeated_move), times)
+            },
+            Explanation::CharacterStreakLimit { whose, repeated_character, times } => {
+                streak_limit_text(locale, whose, &locale.character_name(repeated_character), times)
+            },
+            Explanation::Waits { waits } => {
+                fill(&locale.message(Message::ExplainWaits), &[("waits", &waits_text(locale, waits))])
+            },
+        }
+    }
+}
+
+/// Renders the explanation in English.
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.render(&English))
+    }
+}
+
+/// An `Output` and the explanations for it.
+#[derive(Clone)]
+pub struct TutorialOutput {
+    pub output: io::Output,
+    pub explanations: Vec<Explanation>,
+}
+
+/// A game against a computer that follows a `Script`, which explains what happens.
+pub struct TutorialNZSCGame {
+    game: SinglePlayerNZSCGame,
+    has_explained_waits: bool,
+}
+
+impl TutorialNZSCGame {
+    /// Creates a tutorial where the computer follows `script` (and chooses randomly once it runs out).
+    pub fn new(seed: u32, rules: Rules, script: Script) -> TutorialNZSCGame {
+        TutorialNZSCGame {
+            game: SinglePlayerNZSCGame::with_strategy(seed, rules, Box::new(ScriptedStrategy::new(script))),
+            has_explained_waits: false,
+        }
+    }
+
+    /// Creates the standard tutorial, with the standard rules.
+    ///
+    /// The computer chooses Zombie (up to three times, if you choose it too) and Regenerative,
+    /// destroys your move with Zap in the first round, and then chooses Rampage until it can't anymore.
+    pub fn standard(seed: u32) -> TutorialNZSCGame {
+        let script = Script {
+            characters: vec![Character::Zombie; 3],
+            boosters: vec![Booster::Regenerative],
+            moves: vec![Move::Zap, Move::Rampage, Move::Rampage, Move::Rampage],
+        };
+
+        TutorialNZSCGame::new(seed, Rules::standard(), script)
+    }
+
+    /// The underlying game.
+    pub fn game(&self) -> &SinglePlayerNZSCGame {
+        &self.game
+    }
+
+    pub fn current_output(&self) -> io::Output {
+        self.game.current_output()
+    }
+
+    /// Like `SinglePlayerNZSCGame::next`, but also explains the output.
+    pub fn next(&mut self, answer: io::Answer) -> Result<TutorialOutput, AnswerError> {
+        let rules = self.game.rules();
+        let previous_phase = self.game.phase.clone();
+        let output = self.game.next(answer)?;
+        let mut explanations = vec![];
+
+        for notification in &output.notifications {
+            match *notification {
+                io::Notification::CharacterSelectionAndHeadstart { human_character, computer_character, who_gets_the_headstart } => {
+                    match who_gets_the_headstart {
+                        WhoGetsTheHeadstart::JustHuman => explanations.push(Explanation::Headstart {
+                            winner: human_character,
+                            loser: computer_character,
+                        }),
+                        WhoGetsTheHeadstart::JustComputer => explanations.push(Explanation::Headstart {
+                            winner: computer_character,
+                            loser: human_character,
+                        }),
+                        WhoGetsTheHeadstart::Neither => {},
+                    }
+                },
+                io::Notification::MoveSelectionAndOutcome { human_move, computer_move, who_gets_the_point } => {
+                    explanations.push(Explanation::Point {
+                        human_move,
+                        computer_move,
+                        who_gets_the_point,
+                    });
+
+                    if let (
+                        Phase::MoveChoosing { human: human_before, computer: computer_before },
+                        Phase::MoveChoosing { human: human_after, computer: computer_after },
+                    ) = (&previous_phase, &self.game.phase) {
+                        explain_move(&mut explanations, &rules, Whose::Human, human_move, computer_move, human_before, human_after);
+                        explain_move(&mut explanations, &rules, Whose::Computer, computer_move, human_move, computer_before, computer_after);
+                    }
+                },
+                io::Notification::SameCharacterSelection { both_character } => {
+                    for &whose in &[Whose::Human, Whose::Computer] {
+                        if let Some(streak) = self.game.character_streak(whose) {
+                            if streak.repeated_character == Some(both_character) && streak.times >= rules.character_streak_limit {
+                                explanations.push(Explanation::CharacterStreakLimit {
+                                    whose,
+                                    repeated_character: both_character,
+                                    times: streak.times,
+                                });
+                            }
+                        }
+                    }
+                },
+                io::Notification::CharacterNonexistentPenalty { .. }
+                | io::Notification::CharacterThreeTimesInARowPenalty { .. }
+                | io::Notification::BoosterNonexistentPenalty { .. }
+                | io::Notification::BoosterFromWrongCharacterPenalty { .. }
+                | io::Notification::MoveNonexistentPenalty { .. }
+                | io::Notification::MoveThreeTimesInARowPenalty { .. }
+                | io::Notification::MoveSingleUsePenalty { .. }
+                | io::Notification::MoveDestroyedPenalty { .. }
+                | io::Notification::MoveFromWrongCharacterPenalty { .. }
+                | io::Notification::MoveFromWrongBoosterPenalty { .. } if !self.has_explained_waits => {
+                    self.has_explained_waits = true;
+                    explanations.push(Explanation::Waits {
+                        waits: self.game.waits(Whose::Human).unwrap_or(0),
+                    });
+                },
+                _ => {},
+            }
+        }
+
+        Ok(TutorialOutput {
+            output,
+            explanations,
+        })
+    }
+}
+
+/// Explains what happened to `player`'s moves when they chose `chosen` and their opponent chose `opponent_move`.
+fn explain_move(explanations: &mut Vec<Explanation>, rules: &Rules, whose: Whose, chosen: Move, opponent_move: Move, before: &Player, after: &Player) {
+    for &destroyed_move in after.destroyed_moves.iter().filter(|m| !before.destroyed_moves.contains(m)) {
+        if destroyed_move == chosen && SINGLE_USE_MOVES.contains(&chosen) {
+            explanations.push(Explanation::SingleUse {
+                whose,
+                used_move: chosen,
+            });
+        } else {
+            explanations.push(Explanation::Destroyed {
+                whose,
+                destroyer: opponent_move,
+                destroyed_move,
+            });
+        }
+    }
+
+    let streak = after.move_streak;
+    if streak.repeated_move == Some(chosen)
+        && streak.times >= rules.move_streak_limit
+        && !after.destroyed_moves.contains(&chosen)
+    {
+        explanations.push(Explanation::MoveStreakLimit {
+            whose,
+            repeated_move: chosen,
+            times: streak.times,
+        });
+    }
+}
+
+fn streak_limit_text<L: Locale + ?Sized>(locale: &L, whose: Whose, name: &str, times: u8) -> String {
+    let message = match whose {
+        Whose::Human => Message::ExplainYourStreakLimit,
+        Whose::Computer => Message::ExplainComputerStreakLimit,
+    };
+
+    fill(&locale.message(message), &[
+        ("name", name),
+        ("times", &times.to_string()),
+    ])
+}
@@ -0,0 +1,414 @@
+use super::players::{
+    self,
+    CharacterlessPlayer,
+    BoosterlessPlayer,
+    Player,
+};
+use super::io;
+use super::outcomes;
+use super::rules::Rules;
+use super::errors::{
+    AnswerError,
+    AnswerType,
+};
+use super::penalties::{
+    self,
+    Penalty,
+};
+
+/// A phase of a game between two humans, `a` and `b`.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Phase {
+    CharacterChoosing {
+        a: CharacterlessPlayer,
+        b: CharacterlessPlayer,
+    },
+    BoosterChoosing {
+        a: BoosterlessPlayer,
+        b: BoosterlessPlayer,
+    },
+    MoveChoosing {
+        a: Player,
+        b: Player,
+    },
+    GameOver {
+        a_points: u8,
+        b_points: u8,
+    },
+}
+
+impl Phase {
+    /// The type of answer this phase needs from each player, or `None` if the game is over.
+    pub fn expected_answer_type(&self) -> Option<AnswerType> {
+        match *self {
+            Phase::CharacterChoosing { .. } => Some(AnswerType::CharacterSelection),
+            Phase::BoosterChoosing { .. } => Some(AnswerType::BoosterSelection),
+            Phase::MoveChoosing { .. } => Some(AnswerType::MoveSelection),
+            Phase::GameOver { .. } => None,
+        }
+    }
+}
+
+/// An `Output` for each player.
+///
+/// Each output is from its player's perspective:
+/// the `human` fields of its notifications refer to that player,
+/// and the `computer` fields refer to their opponent.
+/// Render notifications with `Notification::render_against` and `Opponent::Human`,
+/// so they don't mention a computer.
+pub struct TwoPlayerOutput {
+    pub a: io::Output,
+    pub b: io::Output,
+}
+
+/// A game between two humans, who answer each question simultaneously.
+///
+/// If either answer is illegal, that player is penalized,
+/// and both players must answer again (even if the other answer was legal).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TwoPlayerNZSCGame {
+    rules: Rules,
+    pub phase: Phase,
+}
+
+/// Notifications for each player, in the making.
+struct Notifications {
+    a: Vec<io::Notification>,
+    b: Vec<io::Notification>,
+}
+
+impl Notifications {
+    fn new() -> Notifications {
+        Notifications {
+            a: vec![],
+            b: vec![],
+        }
+    }
+
+    /// Notifies both players of something described from `a`'s perspective.
+    fn push_both(&mut self, notification: io::Notification) {
+        self.a.push(notification.clone());
+        self.b.push(notification.swap_perspective());
+    }
+
+    /// Notifies each player of their own penalty and their opponent's penalty.
+    ///
+    /// `b_points` is what `b` got for `a`'s penalty, and `a_points` is what `a` got for `b`'s penalty.
+    fn push_penalties(&mut self, a_penalty: Option<Penalty>, b_points: u8, b_penalty: Option<Penalty>, a_points: u8) {
+        if let Some(penalty) = a_penalty {
+            for notification in penalties::notifications(penalty.notification, b_points) {
+                self.a.push(notification.clone());
+                self.b.push(io::Notification::OpponentPenalty {
+                    penalty: Box::new(notification),
+                });
+            }
+        }
+        if let Some(penalty) = b_penalty {
+            for notification in penalties::notifications(penalty.notification, a_points) {
+                self.b.push(notification.clone());
+                self.a.push(io::Notification::OpponentPenalty {
+                    penalty: Box::new(notification),
+                });
+            }
+        }
+    }
+}
+
+impl TwoPlayerNZSCGame {
+    /// For the standard game, use `Rules::standard()`.
+    pub fn new(rules: Rules) -> TwoPlayerNZSCGame {
+        TwoPlayerNZSCGame {
+            rules,
+            phase: Phase::CharacterChoosing {
+                a: CharacterlessPlayer::with_rules(rules),
+                b: CharacterlessPlayer::with_rules(rules),
+            },
+        }
+    }
+
+    pub fn rules(&self) -> Rules {
+        self.rules
+    }
+
+    /// The questions currently being asked.
+    ///
+    /// If the game is over, there are no questions, and the only notification is `GameOver`.
+    /// Otherwise, there are no notifications.
+    pub fn current_output(&self) -> TwoPlayerOutput {
+        let mut notifications = Notifications::new();
+
+        if let Phase::GameOver { a_points, b_points } = self.phase {
+            notifications.push_both(io::Notification::GameOver {
+                human_points: a_points,
+                computer_points: b_points,
+            });
+        }
+
+        self.output(notifications)
+    }
+
+    /// Fails without changing the game if either answer doesn't answer the current question.
+    /// If both answers are wrong, the error describes `a_answer`.
+    pub fn next(&mut self, a_answer: io::Answer, b_answer: io::Answer) -> Result<TwoPlayerOutput, AnswerError> {
+        let rules = self.rules;
+        let mut notifications = Notifications::new();
+
+        match (self.phase.clone(), a_answer, b_answer) {
+            (
+                Phase::CharacterChoosing { mut a, mut b },
+                io::Answer::CharacterSelection(a_selection),
+                io::Answer::CharacterSelection(b_selection)
+            ) => {
+                match (penalties::validate_character(&a, &rules, a_selection), penalties::validate_character(&b, &rules, b_selection)) {
+                    (Ok(a_character), Ok(b_character)) => {
+                        if a_character == b_character {
+                            a.character_streak.add(a_character);
+                            b.character_streak.add(b_character);
+
+                            notifications.push_both(io::Notification::SameCharacterSelection {
+                                both_character: a_character,
+                            });
+
+                            self.phase = Phase::CharacterChoosing {
+                                a,
+                                b,
+                            };
+                        } else {
+                            let headstart = outcomes::get_headstart(a_character, b_character);
+
+                            a.points += headstart.0;
+                            b.points += headstart.1;
+
+                            let who_gets_the_headstart = match headstart {
+                                outcomes::Headstart(0, 0) => io::WhoGetsTheHeadstart::Neither,
+                                outcomes::Headstart(0, 1) => io::WhoGetsTheHeadstart::JustComputer,
+                                outcomes::Headstart(1, 0) => io::WhoGetsTheHeadstart::JustHuman,
+                                outcomes::Headstart(a, b) => panic!("Illegal headstart: {}-{}!", a, b),
+                            };
+
+                            notifications.push_both(io::Notification::CharacterSelectionAndHeadstart {
+                                human_character: a_character,
+                                computer_character: b_character,
+                                who_gets_the_headstart,
+                            });
+
+                            let mut a = a.to_boosterless_player(a_character);
+                            let mut b = b.to_boosterless_player(b_character);
+
+                            // Players might have incurred penalties before successfully choosing characters.
+                            self.phase = if settle(&rules, &mut a.points, &mut b.points, &mut notifications) {
+                                Phase::GameOver {
+                                    a_points: a.points,
+                                    b_points: b.points,
+                                }
+                            } else {
+                                Phase::BoosterChoosing {
+                                    a,
+                                    b,
+                                }
+                            };
+                        }
+                    },
+                    (a_result, b_result) => {
+                        let (a_penalty, b_penalty) = (a_result.err(), b_result.err());
+
+                        let b_points = a_penalty.as_ref().map_or(0, |penalty| a.penalize_waits(penalty.waits));
+                        let a_points = b_penalty.as_ref().map_or(0, |penalty| b.penalize_waits(penalty.waits));
+                        a.points += a_points;
+                        b.points += b_points;
+
+                        notifications.push_penalties(a_penalty, b_points, b_penalty, a_points);
+                        notifications.push_both(io::Notification::ScoreUpdate {
+                            human_points: a.points,
+                            computer_points: b.points,
+                        });
+
+                        self.phase = if settle(&rules, &mut a.points, &mut b.points, &mut notifications) {
+                            Phase::GameOver {
+                                a_points: a.points,
+                                b_points: b.points,
+                            }
+                        } else {
+                            Phase::CharacterChoosing {
+                                a,
+                                b,
+                            }
+                        };
+                    },
+                }
+            },
+            (
+                Phase::BoosterChoosing { mut a, mut b },
+                io::Answer::BoosterSelection(a_selection),
+                io::Answer::BoosterSelection(b_selection)
+            ) => {
+                match (penalties::validate_booster(&a, &rules, a_selection), penalties::validate_booster(&b, &rules, b_selection)) {
+                    (Ok(a_booster), Ok(b_booster)) => {
+                        notifications.push_both(io::Notification::BoosterSelection {
+                            human_booster: a_booster,
+                            computer_booster: b_booster,
+                        });
+
+                        self.phase = Phase::MoveChoosing {
+                            a: a.to_player(a_booster),
+                            b: b.to_player(b_booster),
+                        };
+                    },
+                    (a_result, b_result) => {
+                        let (a_penalty, b_penalty) = (a_result.err(), b_result.err());
+
+                        let b_points = a_penalty.as_ref().map_or(0, |penalty| a.penalize_waits(penalty.waits));
+                        let a_points = b_penalty.as_ref().map_or(0, |penalty| b.penalize_waits(penalty.waits));
+                        a.points += a_points;
+                        b.points += b_points;
+
+                        notifications.push_penalties(a_penalty, b_points, b_penalty, a_points);
+                        notifications.push_both(io::Notification::ScoreUpdate {
+                            human_points: a.points,
+                            computer_points: b.points,
+                        });
+
+                        self.phase = if settle(&rules, &mut a.points, &mut b.points, &mut notifications) {
+                            Phase::GameOver {
+                                a_points: a.points,
+                                b_points: b.points,
+                            }
+                        } else {
+                            Phase::BoosterChoosing {
+                                a,
+                                b,
+                            }
+                        };
+                    },
+                }
+            },
+            (
+                Phase::MoveChoosing { mut a, mut b },
+                io::Answer::MoveSelection(a_selection),
+                io::Answer::MoveSelection(b_selection)
+            ) => {
+                match (penalties::validate_move(&a, &rules, a_selection), penalties::validate_move(&b, &rules, b_selection)) {
+                    (Ok(a_move), Ok(b_move)) => {
+                        let points = players::play_moves(&mut a, a_move, &mut b, b_move);
+
+                        let who_gets_the_point = match points {
+                            (0, 0) => io::WhoGetsThePoint::Neither,
+                            (0, 1) => io::WhoGetsThePoint::JustComputer,
+                            (1, 0) => io::WhoGetsThePoint::JustHuman,
+                            (1, 1) => io::WhoGetsThePoint::Both,
+                            (a, b) => panic!("Illegal outcome: {}-{}!", a, b),
+                        };
+
+                        notifications.push_both(io::Notification::MoveSelectionAndOutcome {
+                            human_move: a_move,
+                            computer_move: b_move,
+                            who_gets_the_point,
+                        });
+                    },
+                    (a_result, b_result) => {
+                        let (a_penalty, b_penalty) = (a_result.err(), b_result.err());
+
+                        let b_points = a_penalty.as_ref().map_or(0, |penalty| a.penalize_waits(penalty.waits));
+                        let a_points = b_penalty.as_ref().map_or(0, |penalty| b.penalize_waits(penalty.waits));
+                        a.points += a_points;
+                        b.points += b_points;
+
+                        notifications.push_penalties(a_penalty, b_points, b_penalty, a_points);
+                    },
+                }
+
+                notifications.push_both(io::Notification::ScoreUpdate {
+                    human_points: a.points,
+                    computer_points: b.points,
+                });
+
+                self.phase = if settle(&rules, &mut a.points, &mut b.points, &mut notifications) {
+                    Phase::GameOver {
+                        a_points: a.points,
+                        b_points: b.points,
+                    }
+                } else {
+                    Phase::MoveChoosing {
+                        a,
+                        b,
+                    }
+                };
+            },
+            (_, a_answer, b_answer) => {
+                let expected = self.phase.expected_answer_type();
+
+                if expected == Some(AnswerType::from(&a_answer)) {
+                    return Err(AnswerError::unexpected(expected, &b_answer));
+                } else {
+                    return Err(AnswerError::unexpected(expected, &a_answer));
+                }
+            },
+        }
+
+        Ok(self.output(notifications))
+    }
+
+    fn output(&self, notifications: Notifications) -> TwoPlayerOutput {
+        let (a_question, b_question) = match self.phase {
+            Phase::CharacterChoosing { ref a, ref b } => (
+                Some(io::Question::ChooseCharacter { available_characters: a.available_characters_with_rules(&self.rules) }),
+                Some(io::Question::ChooseCharacter { available_characters: b.available_characters_with_rules(&self.rules) }),
+            ),
+            Phase::BoosterChoosing { ref a, ref b } => (
+                Some(io::Question::ChooseBooster { available_boosters: a.available_boosters() }),
+                Some(io::Question::ChooseBooster { available_boosters: b.available_boosters() }),
+            ),
+            Phase::MoveChoosing { ref a, ref b } => (
+                Some(io::Question::ChooseMove { available_moves: a.available_moves_with_rules(&self.rules) }),
+                Some(io::Question::ChooseMove { available_moves: b.available_moves_with_rules(&self.rules) }),
+            ),
+            Phase::GameOver { .. } => (None, None),
+        };
+
+        TwoPlayerOutput {
+            a: io::Output {
+                question: a_question,
+                notifications: notifications.a,
+            },
+            b: io::Output {
+                question: b_question,
+                notifications: notifications.b,
+            },
+        }
+    }
+}
+
+impl Default for TwoPlayerNZSCGame {
+    fn default() -> TwoPlayerNZSCGame {
+        TwoPlayerNZSCGame::new(Rules::standard())
+    }
+}
+
+/// Checks whether either player has won, notifying both players if so.
+///
+/// If both players have enough points to win, they are tied, so both are set back.
+/// Returns whether the game is over.
+fn settle(rules: &Rules, a_points: &mut u8, b_points: &mut u8, notifications: &mut Notifications) -> bool {
+    if *a_points >= rules.points_to_win || *b_points >= rules.points_to_win {
+        if *a_points == *b_points {
+            notifications.push_both(io::Notification::TiebreakingScoreSetback {
+                both_points: *a_points,
+            });
+
+            *a_points = rules.tiebreak_points;
+            *b_points = rules.tiebreak_points;
+
+            false
+        } else {
+            notifications.push_both(io::Notification::GameOver {
+                human_points: *a_points,
+                computer_points: *b_points,
+            });
+
+            true
+        }
+    } else {
+        false
+    }
+}
@@ -0,0 +1,62 @@
+//! Bindings for running the game in the browser with `wasm-bindgen`.
+//!
+//! Outputs and answers are converted to and from plain JS objects with the same shape
+//! as their `serde` representations. For example, choosing Ninja is
+//! `{ CharacterSelection: { Character: "Ninja" } }`.
+
+use wasm_bindgen::prelude::*;
+use serde_wasm_bindgen;
+
+use super::single_player_game::SinglePlayerNZSCGame;
+use super::rules::Rules;
+use super::io;
+
+/// A `SinglePlayerNZSCGame` (with the standard rules) for JS.
+#[wasm_bindgen(js_name = SinglePlayerNZSCGame)]
+pub struct WasmSinglePlayerNZSCGame {
+    game: SinglePlayerNZSCGame,
+}
+
+#[wasm_bindgen(js_class = SinglePlayerNZSCGame)]
+impl WasmSinglePlayerNZSCGame {
+    #[wasm_bindgen(constructor)]
+    pub fn new(seed: u32) -> WasmSinglePlayerNZSCGame {
+        WasmSinglePlayerNZSCGame {
+            game: SinglePlayerNZSCGame::new(seed, Rules::standard()),
+        }
+    }
+
+    /// The current `Output`, as a JS object.
+    #[wasm_bindgen(js_name = initialOutput)]
+    pub fn initial_output(&self) -> Result<JsValue, JsValue> {
+        to_js(&self.game.current_output())
+    }
+
+    /// Answers the current question with an `Answer` JS object, and returns the resulting `Output`.
+    ///
+    /// Throws if `answer` isn't a valid `Answer`, or doesn't answer the current question.
+    pub fn next(&mut self, answer: JsValue) -> Result<JsValue, JsValue> {
+        let answer: io::Answer = serde_wasm_bindgen::from_value(answer)?;
+        let output = self.game.next(answer)
+            .map_err(|error| JsValue::from_str(&error.to_string()))?;
+
+        to_js(&output)
+    }
+
+    /// Answers the current question with text typed by the user (see `Question::parse_answer`).
+    #[wasm_bindgen(js_name = nextText)]
+    pub fn next_text(&mut self, text: &str) -> Result<JsValue, JsValue> {
+        let answer = match self.game.current_output().question {
+            Some(question) => question.parse_answer(text),
+            None => return Err(JsValue::from_str(&super::errors::AnswerError::GameOver.to_string())),
+        };
+        let output = self.game.next(answer)
+            .map_err(|error| JsValue::from_str(&error.to_string()))?;
+
+        to_js(&output)
+    }
+}
+
+fn to_js(output: &io::Output) -> Result<JsValue, JsValue> {
+    serde_wasm_bindgen::to_value(output).map_err(JsValue::from)
+}
@@ -0,0 +1,118 @@
+extern crate nzsc_single_player;
+
+use std::sync::{
+    Arc,
+    Mutex,
+};
+use std::thread;
+
+use nzsc_single_player::strategies::{
+    ComputerStrategy,
+    AdaptiveStrategy,
+    OpponentModel,
+};
+use nzsc_single_player::single_player_game::SinglePlayerNZSCGame;
+use nzsc_single_player::players::{
+    CharacterlessPlayer,
+    Player,
+};
+use nzsc_single_player::prfg::PseudorandomFloatGenerator;
+use nzsc_single_player::rules::Rules;
+use nzsc_single_player::characters::Character;
+use nzsc_single_player::boosters::Booster;
+use nzsc_single_player::moves::Move;
+use nzsc_single_player::outcomes;
+
+fn probability_of<T: PartialEq>(prediction: &[(T, f64)], option: T) -> f64 {
+    prediction.iter()
+        .find(|&(predicted, _)| *predicted == option)
+        .map(|&(_, probability)| probability)
+        .unwrap()
+}
+
+#[test]
+fn an_empty_model_predicts_uniformly() {
+    let model = OpponentModel::new();
+    let prediction = model.predict_moves(&[Move::Kick, Move::Nunchucks, Move::NinjaSword, Move::ShadowSlip]);
+
+    assert!(prediction.iter().all(|&(_, probability)| (probability - 0.25).abs() < 1e-9));
+}
+
+#[test]
+fn unseen_options_are_still_possible() {
+    let mut model = OpponentModel::new();
+    for _ in 0..10 {
+        model.record_character(Character::Zombie);
+    }
+    let prediction = model.predict_characters(&[Character::Zombie, Character::Ninja]);
+
+    // Each count is smoothed by adding one: 11 / 12 and 1 / 12.
+    assert!((probability_of(&prediction, Character::Zombie) - 11.0 / 12.0).abs() < 1e-9);
+    assert!((probability_of(&prediction, Character::Ninja) - 1.0 / 12.0).abs() < 1e-9);
+}
+
+#[test]
+fn moves_are_predicted_from_what_followed_the_last_move() {
+    let mut model = OpponentModel::new();
+    model.record_booster(Booster::Shadow);
+    // Kick is the favorite, but Nunchucks always follows Ninja Sword.
+    for &opponent_move in &[Move::Kick, Move::Kick, Move::Kick, Move::NinjaSword, Move::Nunchucks, Move::Kick, Move::NinjaSword] {
+        model.record_move(opponent_move);
+    }
+    let moves = [Move::Kick, Move::Nunchucks, Move::NinjaSword];
+    let prediction = model.predict_moves(&moves);
+
+    assert!(probability_of(&prediction, Move::Nunchucks) > probability_of(&prediction, Move::Kick));
+
+    // Nothing has followed Nunchucks except Kick, so without Kick, the prediction falls back to frequencies.
+    model.record_move(Move::Nunchucks);
+    let prediction = model.predict_moves(&[Move::NinjaSword, Move::ShadowSlip]);
+
+    assert!(probability_of(&prediction, Move::NinjaSword) > probability_of(&prediction, Move::ShadowSlip));
+}
+
+fn player(character: Character, booster: Booster) -> Player {
+    CharacterlessPlayer::with_rules(Rules::standard())
+        .to_boosterless_player(character)
+        .to_player(booster)
+}
+
+#[test]
+fn counters_a_biased_opponent() {
+    let computer = player(Character::Ninja, Booster::Shadow);
+    let human = player(Character::Zombie, Booster::Regenerative);
+    let model = Arc::new(Mutex::new(OpponentModel::new()));
+    for _ in 0..50 {
+        model.lock().unwrap().record_move(Move::Muscle);
+    }
+    let beats_muscle = |chosen: Move| outcomes::get_move_points(chosen, computer.booster, Move::Muscle, human.booster) == (1, 0);
+    assert!(computer.available_moves().into_iter().any(&beats_muscle));
+
+    let mut always_counter = AdaptiveStrategy::with_model(Arc::clone(&model)).with_nash_probability(0.0);
+    let mut mixed = AdaptiveStrategy::with_model(model);
+    let mut mixed_counters = 0;
+    for seed in 1..201 {
+        let mut prfg = PseudorandomFloatGenerator::new(seed);
+        assert!(beats_muscle(always_counter.choose_move(&computer, &human, &Rules::standard(), &[], &mut prfg)));
+
+        if beats_muscle(mixed.choose_move(&computer, &human, &Rules::standard(), &[], &mut prfg)) {
+            mixed_counters += 1;
+        }
+    }
+
+    // The mixed strategy usually counters, but not always (the equilibrium strategy never beats Muscle).
+    assert!(mixed_counters > 100 && mixed_counters < 200, "countered {} times", mixed_counters);
+}
+
+#[test]
+fn adaptive_games_can_move_between_threads() {
+    let mut game = SinglePlayerNZSCGame::with_strategy(1, Rules::standard(), Box::new(AdaptiveStrategy::new()));
+
+    let handle = thread::spawn(move || {
+        game.next(nzsc_single_player::io::Answer::CharacterSelection(
+            nzsc_single_player::io::CharacterSelection::Character(Character::Ninja)
+        )).is_ok()
+    });
+
+    assert!(handle.join().unwrap());
+}
@@ -0,0 +1,41 @@
+extern crate nzsc_single_player;
+
+use nzsc_single_player::advisor;
+use nzsc_single_player::single_player_game::{
+    SinglePlayerNZSCGame,
+    Phase,
+};
+use nzsc_single_player::rules::Rules;
+use nzsc_single_player::characters::Character;
+use nzsc_single_player::boosters::Booster;
+use nzsc_single_player::io::{
+    Answer,
+    CharacterSelection,
+    BoosterSelection,
+};
+
+#[test]
+fn advises_only_for_the_current_question() {
+    let mut game = SinglePlayerNZSCGame::new(42, Rules::standard());
+
+    let characters = advisor::advise_character(&game.phase, &game.rules());
+    assert_eq!(characters.len(), 4);
+    assert!(characters.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+    assert!(advisor::advise(&game.phase, &game.rules()).is_empty());
+
+    while let Phase::CharacterChoosing { .. } = game.phase {
+        game.next(Answer::CharacterSelection(CharacterSelection::Character(Character::Zombie))).unwrap();
+    }
+    let boosters = advisor::advise_booster(&game.phase, &game.rules());
+    assert_eq!(boosters[0].0, Booster::Regenerative);
+
+    game.next(Answer::BoosterSelection(BoosterSelection::Booster(Booster::Regenerative))).unwrap();
+    let moves = advisor::advise(&game.phase, &game.rules());
+    match game.phase {
+        Phase::MoveChoosing { ref human, .. } => {
+            assert!(!moves.is_empty());
+            assert_eq!(moves.len(), human.available_moves().len());
+        },
+        _ => panic!("Expected moves to be chosen"),
+    }
+}
@@ -0,0 +1,32 @@
+extern crate nzsc_single_player;
+
+use nzsc_single_player::balance;
+use nzsc_single_player::characters::Character;
+use nzsc_single_player::outcomes;
+
+#[test]
+fn report_is_consistent_with_the_outcome_tables() {
+    let report = balance::analyze();
+
+    assert_eq!(report.moves.len(), 28);
+    for record in &report.moves {
+        assert_eq!(record.wins + record.losses + record.ties + record.trades, 27);
+    }
+
+    for cycle in &report.cycles {
+        for i in 0..3 {
+            assert_eq!(outcomes::get_points(vec![cycle[i], cycle[(i + 1) % 3]]), vec![1, 0]);
+        }
+    }
+    assert!(report.dominance.iter().all(|&(a, b)| a != b));
+
+    assert_eq!(report.loadouts.len(), 12);
+    assert_eq!(report.headstarts, vec![
+        (Character::Ninja, Character::Samurai),
+        (Character::Samurai, Character::Clown),
+        (Character::Clown, Character::Ninja),
+    ]);
+
+    assert!(report.to_markdown().contains("| Regenerate | 20 | 0 | 6 | 1 | 20 |"));
+    assert_eq!(report.moves_csv().lines().count(), 29);
+}
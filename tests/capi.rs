@@ -0,0 +1,62 @@
+#![cfg(feature = "capi")]
+
+extern crate nzsc_single_player;
+
+use std::ffi::{
+    CStr,
+    CString,
+};
+use std::ptr;
+
+use nzsc_single_player::capi::*;
+use nzsc_single_player::characters::Character;
+use nzsc_single_player::moves::Move;
+
+#[test]
+fn plays_a_game_through_the_c_interface() {
+    unsafe {
+        let game = nzsc_game_new(42);
+        assert_eq!(nzsc_game_question_kind(game), NZSC_QUESTION_CHARACTER);
+        assert_eq!(nzsc_game_choose_move(game, Move::Kick.to_u8()), NZSC_ERROR_WRONG_ANSWER_TYPE);
+        assert_eq!(nzsc_game_choose_character(game, 200), NZSC_ERROR_INVALID_ID);
+
+        let mut ids = [0u8; 32];
+        let mut turns = 0;
+        while nzsc_game_question_kind(game) != NZSC_QUESTION_NONE && turns < 200 {
+            let count = nzsc_game_available_options(game, ids.as_mut_ptr(), ids.len());
+            let status = match nzsc_game_question_kind(game) {
+                NZSC_QUESTION_CHARACTER => nzsc_game_choose_character(game, ids[0]),
+                NZSC_QUESTION_BOOSTER => nzsc_game_choose_booster(game, ids[0]),
+                _ if count == 0 => {
+                    let text = CString::new("nothing").unwrap();
+                    nzsc_game_answer_text(game, text.as_ptr())
+                },
+                _ => nzsc_game_choose_move(game, ids[turns % count]),
+            };
+            assert_eq!(status, NZSC_OK);
+            turns += 1;
+        }
+
+        assert_eq!(nzsc_game_choose_character(game, Character::Ninja.to_u8()), NZSC_ERROR_GAME_OVER);
+
+        let json = nzsc_game_output_json(game);
+        assert!(CStr::from_ptr(json).to_str().unwrap().contains("GameOver"));
+        nzsc_string_free(json);
+
+        nzsc_game_free(game);
+    }
+}
+
+#[test]
+fn rejects_null_pointers() {
+    unsafe {
+        assert_eq!(nzsc_game_answer_text(ptr::null_mut(), ptr::null()), NZSC_ERROR_NULL);
+        assert_eq!(nzsc_game_question_kind(ptr::null()), NZSC_ERROR_NULL);
+        // Null games are reported before invalid ids.
+        assert_eq!(nzsc_game_choose_character(ptr::null_mut(), 255), NZSC_ERROR_NULL);
+        assert_eq!(nzsc_game_choose_booster(ptr::null_mut(), 255), NZSC_ERROR_NULL);
+        assert_eq!(nzsc_game_choose_move(ptr::null_mut(), Move::Kick.to_u8()), NZSC_ERROR_NULL);
+        assert!(nzsc_game_output_json(ptr::null()).is_null());
+        nzsc_game_free(ptr::null_mut());
+    }
+}
@@ -0,0 +1,84 @@
+#![cfg(feature = "cli")]
+
+use std::io::Write;
+use std::process::{
+    Command,
+    Output,
+    Stdio,
+};
+
+/// Runs `nzsc` with `args`, typing `input`.
+fn nzsc(args: &[&str], input: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_nzsc"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8(output.stderr.clone()).unwrap()
+}
+
+#[test]
+fn games_with_the_same_seed_are_the_same() {
+    let first = nzsc(&["--seed", "5"], "ninja\n");
+    let second = nzsc(&["--seed", "5"], "ninja\n");
+
+    assert!(first.status.success());
+    assert!(stdout(&first).starts_with("Welcome to NZSC! (seed: 5)"));
+    assert!(stdout(&first).contains("chose Ninja"));
+    assert_eq!(stdout(&first), stdout(&second));
+}
+
+#[test]
+fn games_without_a_seed_get_a_nonzero_one() {
+    let output = nzsc(&[], "");
+
+    assert!(output.status.success());
+    assert!(stdout(&output).starts_with("Welcome to NZSC! (seed: "));
+    assert!(!stdout(&output).starts_with("Welcome to NZSC! (seed: 0)"));
+}
+
+#[test]
+fn a_zero_seed_is_rejected() {
+    let output = nzsc(&["--seed", "0"], "");
+
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr(&output).starts_with("The seed can't be 0.\nUsage: nzsc"));
+    assert!(stdout(&output).is_empty());
+}
+
+#[test]
+fn invalid_arguments_are_rejected() {
+    let cases: [(&[&str], &str); 4] = [
+        (&["--seed"], "Missing value for --seed."),
+        (&["--seed", "-1"], "Invalid seed: -1"),
+        (&["--seed", "4294967296"], "Invalid seed: 4294967296"),
+        (&["--speed", "5"], "Unknown argument: --speed"),
+    ];
+
+    for &(args, message) in cases.iter() {
+        let output = nzsc(args, "");
+
+        assert_eq!(output.status.code(), Some(2), "{:?}", args);
+        assert!(stderr(&output).starts_with(message), "{:?} printed {:?}", args, stderr(&output));
+    }
+}
+
+#[test]
+fn help_prints_the_usage() {
+    let output = nzsc(&["--help"], "");
+
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "Usage: nzsc [--seed <seed>]\n");
+}
@@ -0,0 +1,75 @@
+extern crate nzsc_single_player;
+
+use nzsc_single_player::fuzzy::{
+    self,
+    Candidate,
+};
+use nzsc_single_player::characters::Character;
+use nzsc_single_player::boosters::Booster;
+use nzsc_single_player::moves::Move;
+
+const CHARACTERS: [Character; 4] = [
+    Character::Ninja,
+    Character::Zombie,
+    Character::Samurai,
+    Character::Clown,
+];
+
+const NINJA_MOVES: [Move; 7] = [
+    Move::Kick,
+    Move::NinjaSword,
+    Move::Nunchucks,
+    Move::ShadowFireball,
+    Move::ShadowSlip,
+    Move::LightningFastKarateChop,
+    Move::MustacheMash,
+];
+
+#[test]
+fn exact_names_ignore_case_and_whitespace() {
+    assert_eq!(fuzzy::rank("  nInJa ", &CHARACTERS), vec![Candidate { option: Character::Ninja, score: 0 }]);
+    assert_eq!(fuzzy::best_match("ninja sword", &NINJA_MOVES), Some(Candidate { option: Move::NinjaSword, score: 0 }));
+}
+
+#[test]
+fn moustache_and_mustache_are_the_same() {
+    assert_eq!(fuzzy::best_match("Moustache Mash", &NINJA_MOVES).map(|c| c.option), Some(Move::MustacheMash));
+    assert_eq!(fuzzy::best_match("Mustachio", &[Booster::Moustachio]).map(|c| c.option), Some(Booster::Moustachio));
+}
+
+#[test]
+fn initials_and_prefixes_match() {
+    assert_eq!(fuzzy::best_match("lfkc", &NINJA_MOVES), Some(Candidate { option: Move::LightningFastKarateChop, score: 1 }));
+    assert_eq!(fuzzy::best_match("nunchuck", &NINJA_MOVES), Some(Candidate { option: Move::Nunchucks, score: 2 }));
+    assert_eq!(fuzzy::best_match("sam", &CHARACTERS), Some(Candidate { option: Character::Samurai, score: 2 }));
+}
+
+#[test]
+fn a_few_typos_match() {
+    assert_eq!(fuzzy::best_match("kik", &NINJA_MOVES), Some(Candidate { option: Move::Kick, score: 3 }));
+    assert_eq!(fuzzy::best_match("clwon", &CHARACTERS), Some(Candidate { option: Character::Clown, score: 4 }));
+    // Longer names allow more typos.
+    assert_eq!(fuzzy::best_match("nunchux", &NINJA_MOVES), Some(Candidate { option: Move::Nunchucks, score: 5 }));
+}
+
+#[test]
+fn too_many_typos_dont_match() {
+    assert!(fuzzy::rank("zoq", &[Move::Zap]).is_empty());
+    assert!(fuzzy::rank("zzzz", &CHARACTERS).is_empty());
+}
+
+#[test]
+fn nothing_matches_empty_text() {
+    assert!(fuzzy::rank("", &CHARACTERS).is_empty());
+    assert!(fuzzy::rank("   ", &CHARACTERS).is_empty());
+    assert_eq!(fuzzy::best_match("", &CHARACTERS), None);
+}
+
+#[test]
+fn ties_keep_their_order_but_have_no_best_match() {
+    assert_eq!(fuzzy::rank("shadow", &NINJA_MOVES), vec![
+        Candidate { option: Move::ShadowFireball, score: 2 },
+        Candidate { option: Move::ShadowSlip, score: 2 },
+    ]);
+    assert_eq!(fuzzy::best_match("shadow", &NINJA_MOVES), None);
+}
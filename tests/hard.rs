@@ -0,0 +1,107 @@
+extern crate nzsc_single_player;
+
+use nzsc_single_player::strategies::{
+    ComputerStrategy,
+    HardStrategy,
+};
+use nzsc_single_player::strategies::hard;
+use nzsc_single_player::players::{
+    CharacterlessPlayer,
+    Player,
+};
+use nzsc_single_player::prfg::PseudorandomFloatGenerator;
+use nzsc_single_player::rules::Rules;
+use nzsc_single_player::characters::{
+    Character,
+    CHARACTERS,
+};
+use nzsc_single_player::boosters::Booster;
+use nzsc_single_player::moves::Move;
+use nzsc_single_player::outcomes;
+
+/// A player with every move except `remaining` destroyed.
+fn player_with_moves(character: Character, booster: Booster, remaining: &[Move]) -> Player {
+    let mut player = CharacterlessPlayer::with_rules(Rules::standard())
+        .to_boosterless_player(character)
+        .to_player(booster);
+    player.destroyed_moves = player.available_moves().into_iter()
+        .filter(|available_move| !remaining.contains(available_move))
+        .collect();
+
+    player
+}
+
+fn all_moves(character: Character, booster: Booster) -> Vec<Move> {
+    let mut moves = character.get_moves();
+    moves.extend(booster.get_moves());
+
+    moves
+}
+
+#[test]
+fn takes_a_guaranteed_point() {
+    let mut prfg = PseudorandomFloatGenerator::new(5);
+    let mut checked = 0;
+
+    for &character in CHARACTERS.iter() {
+        for booster in character.get_boosters() {
+            let player = player_with_moves(character, booster, &all_moves(character, booster));
+
+            for &opponent_character in CHARACTERS.iter() {
+                for opponent_booster in opponent_character.get_boosters() {
+                    for opponent_move in all_moves(opponent_character, opponent_booster) {
+                        let opponent = player_with_moves(opponent_character, opponent_booster, &[opponent_move]);
+                        let wins = |chosen: Move| {
+                            outcomes::get_move_points(chosen, booster, opponent_move, opponent_booster) == (1, 0)
+                        };
+
+                        if !player.available_moves().into_iter().any(&wins) {
+                            continue;
+                        }
+
+                        let chosen = HardStrategy::with_depth(1).choose_move(&player, &opponent, &Rules::standard(), &[], &mut prfg);
+                        assert!(
+                            wins(chosen),
+                            "{} ({}) chose {} instead of beating {} ({})",
+                            character, booster, chosen, opponent_move, opponent_booster
+                        );
+                        checked += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    assert!(checked > 0);
+}
+
+#[test]
+fn chooses_the_only_available_move() {
+    let player = player_with_moves(Character::Samurai, Booster::Strong, &[Move::Helmet]);
+    let opponent = player_with_moves(Character::Ninja, Booster::Shadow, &all_moves(Character::Ninja, Booster::Shadow));
+    let mut prfg = PseudorandomFloatGenerator::new(5);
+
+    assert_eq!(HardStrategy::new().choose_move(&player, &opponent, &Rules::standard(), &[], &mut prfg), Move::Helmet);
+}
+
+#[test]
+fn evaluates_each_available_move_once() {
+    let player = player_with_moves(Character::Zombie, Booster::Regenerative, &all_moves(Character::Zombie, Booster::Regenerative));
+    let opponent = player_with_moves(Character::Clown, Booster::Backwards, &all_moves(Character::Clown, Booster::Backwards));
+    let evaluations = hard::evaluate_moves(&player, &opponent, &Rules::standard(), 2);
+
+    let evaluated: Vec<Move> = evaluations.iter().map(|&(evaluated_move, _)| evaluated_move).collect();
+    assert_eq!(evaluated, player.available_moves());
+    assert!(evaluations.iter().all(|&(_, value)| value.is_finite()));
+}
+
+#[test]
+fn never_chooses_no_booster() {
+    let player = CharacterlessPlayer::with_rules(Rules::standard()).to_boosterless_player(Character::Clown);
+    let opponent = CharacterlessPlayer::with_rules(Rules::standard()).to_boosterless_player(Character::Ninja);
+
+    for seed in 1..20 {
+        let mut prfg = PseudorandomFloatGenerator::new(seed);
+        assert_ne!(HardStrategy::new().choose_booster(&player, &opponent, &Rules::standard(), &[], &mut prfg), Booster::None);
+    }
+}
@@ -0,0 +1,261 @@
+extern crate nzsc_single_player;
+
+use nzsc_single_player::locale::{
+    self,
+    English,
+    Locale,
+    Message,
+};
+use nzsc_single_player::tutorial::{
+    Explanation,
+    Whose,
+};
+use nzsc_single_player::rules::Rules;
+use nzsc_single_player::characters::Character;
+use nzsc_single_player::boosters::Booster;
+use nzsc_single_player::moves::Move;
+use nzsc_single_player::io::{
+    Notification,
+    WhoGetsThePoint,
+};
+
+/// English, but with names in capitals.
+struct Loud;
+
+impl Locale for Loud {
+    fn character_name(&self, character: Character) -> String {
+        English.character_name(character).to_uppercase()
+    }
+
+    fn booster_name(&self, booster: Booster) -> String {
+        English.booster_name(booster).to_uppercase()
+    }
+
+    fn move_name(&self, selected_move: Move) -> String {
+        English.move_name(selected_move).to_uppercase()
+    }
+
+    fn message(&self, message: Message) -> String {
+        English.message(message)
+    }
+}
+
+/// English, but with Spanish character names.
+struct Spanish;
+
+impl Locale for Spanish {
+    fn character_name(&self, character: Character) -> String {
+        match character {
+            Character::Ninja => "Ninja".to_string(),
+            Character::Zombie => "Zombi".to_string(),
+            Character::Samurai => "Samurái".to_string(),
+            Character::Clown => "Payaso".to_string(),
+        }
+    }
+
+    fn character_aliases(&self, character: Character) -> Vec<String> {
+        match character {
+            Character::Clown => vec!["Niño Payaso".to_string()],
+            _ => vec![],
+        }
+    }
+
+    fn booster_name(&self, booster: Booster) -> String {
+        English.booster_name(booster)
+    }
+
+    fn move_name(&self, selected_move: Move) -> String {
+        English.move_name(selected_move)
+    }
+
+    fn message(&self, message: Message) -> String {
+        English.message(message)
+    }
+}
+
+const MESSAGES: [Message; 54] = [
+    Message::CharacterSelectionAndHeadstart,
+    Message::HeadstartNeither,
+    Message::HeadstartHuman,
+    Message::HeadstartComputer,
+    Message::SameCharacterSelection,
+    Message::BoosterSelection,
+    Message::MoveSelectionAndOutcome,
+    Message::PointNeither,
+    Message::PointHuman,
+    Message::PointComputer,
+    Message::PointBoth,
+    Message::ScoreUpdate,
+    Message::TiebreakingScoreSetback,
+    Message::GameOver,
+    Message::HumanWins,
+    Message::ComputerWins,
+    Message::Penalty,
+    Message::OpponentPenalty,
+    Message::NotEnoughWaits,
+    Message::OpponentNotEnoughWaits,
+    Message::NonexistentCharacter,
+    Message::NonexistentBooster,
+    Message::NonexistentMove,
+    Message::TooManyTimesInARow,
+    Message::SingleUse,
+    Message::Destroyed,
+    Message::FromWrongCharacter,
+    Message::FromWrongBooster,
+    Message::OneWait,
+    Message::ManyWaits,
+    Message::ChooseCharacter,
+    Message::ChooseBooster,
+    Message::ChooseMove,
+    Message::ListSeparator,
+    Message::ExplainHeadstart,
+    Message::ExplainPoint,
+    Message::ExplainNoPoint,
+    Message::ExplainBothPoints,
+    Message::ExplainYourMoveDestroyed,
+    Message::ExplainComputerMoveDestroyed,
+    Message::ExplainYourSingleUse,
+    Message::ExplainComputerSingleUse,
+    Message::ExplainYourStreakLimit,
+    Message::ExplainComputerStreakLimit,
+    Message::ExplainWaits,
+    Message::TwoPlayerCharacterSelectionAndHeadstart,
+    Message::TwoPlayerHeadstartOpponent,
+    Message::TwoPlayerBoosterSelection,
+    Message::TwoPlayerMoveSelectionAndOutcome,
+    Message::TwoPlayerPointOpponent,
+    Message::TwoPlayerScoreUpdate,
+    Message::TwoPlayerGameOver,
+    Message::TwoPlayerOpponentWins,
+    Message::TwoPlayerNotEnoughWaits,
+];
+
+/// The placeholders in `template`, in order.
+fn placeholders(template: &str) -> Vec<&str> {
+    template.split('{')
+        .skip(1)
+        .map(|part| &part[..part.find('}').expect("Placeholders are closed")])
+        .collect()
+}
+
+#[test]
+fn fill_replaces_each_placeholder() {
+    assert_eq!(
+        locale::fill("{a} and {b}, then {a} again", &[("a", "1"), ("b", "2")]),
+        "1 and 2, then 1 again",
+    );
+}
+
+#[test]
+fn fill_keeps_unknown_placeholders() {
+    assert_eq!(locale::fill("{a} {b} {", &[("a", "1")]), "1 {b} {");
+    assert_eq!(locale::fill("no placeholders", &[("a", "1")]), "no placeholders");
+}
+
+#[test]
+fn fill_doesnt_fill_in_values() {
+    assert_eq!(
+        locale::fill("{name} costs {waits}.", &[("name", "{waits}"), ("waits", "4 waits")]),
+        "{waits} costs 4 waits.",
+    );
+}
+
+#[test]
+fn names_users_typed_are_rendered_as_typed() {
+    let notification = Notification::MoveNonexistentPenalty {
+        attempted_move_name: "{waits} {explanation}".to_string(),
+    };
+
+    assert_eq!(
+        notification.to_string(),
+        "There's no move called \"{waits} {explanation}\". That costs 4 waits.",
+    );
+}
+
+#[test]
+fn non_ascii_names_are_matched_ignoring_case_and_whitespace() {
+    assert_eq!(Spanish.parse_character("SAMURÁI"), Some(Character::Samurai));
+    assert_eq!(Spanish.parse_character("samurái"), Some(Character::Samurai));
+    assert_eq!(Spanish.parse_character("NIÑO  payaso"), Some(Character::Clown));
+    // "à" (U+00E0) is encoded as 0xC3 0xA0, and 0xA0 on its own is a no-break space.
+    assert_eq!(Spanish.parse_character("Samurài"), None);
+}
+
+#[test]
+fn english_templates_only_use_known_placeholders() {
+    let known = [
+        "human", "computer", "headstart", "character", "point", "points", "tiebreak_points", "winner", "loser",
+        "explanation", "waits", "name", "limit", "options", "destroyer", "times",
+    ];
+
+    for &message in MESSAGES.iter() {
+        let template = English.message(message);
+
+        assert!(!template.is_empty(), "{:?} is empty", message);
+        for placeholder in placeholders(&template) {
+            assert!(known.contains(&placeholder), "{:?} has an unknown placeholder {{{}}}", message, placeholder);
+        }
+    }
+}
+
+#[test]
+fn english_waits_are_pluralized() {
+    assert_eq!(locale::waits_text(&English, 1), "1 wait");
+    assert_eq!(locale::waits_text(&English, 0), "0 waits");
+    assert_eq!(locale::waits_text(&English, 4), "4 waits");
+}
+
+#[test]
+fn notifications_are_rendered_with_the_locales_names() {
+    let rules = Rules::standard();
+    let outcome = Notification::MoveSelectionAndOutcome {
+        human_move: Move::Kick,
+        computer_move: Move::Nunchucks,
+        who_gets_the_point: WhoGetsThePoint::JustComputer,
+    };
+    let penalty = Notification::MoveSingleUsePenalty {
+        attempted_move: Move::Zap,
+    };
+
+    assert_eq!(
+        Loud.render_notification(&outcome, &rules),
+        "You chose KICK, the computer chose NUNCHUCKS. The computer gets the point.",
+    );
+    assert_eq!(Loud.render_notification(&penalty, &rules), "ZAP can only be used once. That costs 4 waits.");
+    assert_eq!(English.render_notification(&outcome, &rules), outcome.to_string());
+}
+
+#[test]
+fn notifications_are_rendered_with_the_rules_passed_in() {
+    let rules = Rules { character_streak_limit: 2, three_times_in_a_row_penalty: 1, ..Rules::standard() };
+    let penalty = Notification::CharacterThreeTimesInARowPenalty {
+        attempted_character: Character::Ninja,
+    };
+
+    assert_eq!(
+        English.render_notification(&penalty, &rules),
+        "Ninja can't be chosen more than 2 times in a row. That costs 1 wait.",
+    );
+}
+
+#[test]
+fn explanations_are_rendered_with_the_locales_names() {
+    let point = Explanation::Point {
+        human_move: Move::Kick,
+        computer_move: Move::Nunchucks,
+        who_gets_the_point: WhoGetsThePoint::JustComputer,
+    };
+    let streak = Explanation::MoveStreakLimit {
+        whose: Whose::Computer,
+        repeated_move: Move::Rampage,
+        times: 3,
+    };
+
+    assert_eq!(point.render(&Loud), "NUNCHUCKS beats KICK.");
+    assert_eq!(point.to_string(), "Nunchucks beats Kick.");
+    assert_eq!(
+        streak.render(&Loud),
+        "The computer has chosen RAMPAGE 3 times in a row, so it can't choose it next time.",
+    );
+    assert_eq!(Explanation::Waits { waits: 1 }.render(&Loud), Explanation::Waits { waits: 1 }.to_string());
+}
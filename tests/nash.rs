@@ -0,0 +1,130 @@
+extern crate nzsc_single_player;
+
+use nzsc_single_player::nash::{
+    self,
+    Equilibrium,
+};
+use nzsc_single_player::players::{
+    CharacterlessPlayer,
+    Player,
+};
+use nzsc_single_player::rules::Rules;
+use nzsc_single_player::characters::Character;
+use nzsc_single_player::boosters::Booster;
+use nzsc_single_player::outcomes;
+
+const TOLERANCE: f64 = 1e-6;
+
+fn probabilities<T>(strategy: &[(T, f64)]) -> Vec<f64> {
+    strategy.iter().map(|&(_, probability)| probability).collect()
+}
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!((actual - expected).abs() < TOLERANCE, "expected {}, got {}", expected, actual);
+}
+
+/// Checks that neither player can do better than `equilibrium.value` by switching to a single choice.
+fn assert_equilibrium<T>(equilibrium: &Equilibrium<T>, payoffs: &[Vec<f64>]) {
+    let player = probabilities(&equilibrium.player_strategy);
+    let opponent = probabilities(&equilibrium.opponent_strategy);
+
+    assert_close(player.iter().sum(), 1.0);
+    assert_close(opponent.iter().sum(), 1.0);
+
+    for j in 0..opponent.len() {
+        let guaranteed: f64 = payoffs.iter().zip(&player).map(|(row, probability)| row[j] * probability).sum();
+        assert!(guaranteed > equilibrium.value - TOLERANCE, "the opponent can get the player down to {}", guaranteed);
+    }
+    for (i, row) in payoffs.iter().enumerate() {
+        let best_response: f64 = row.iter().zip(&opponent).map(|(payoff, probability)| payoff * probability).sum();
+        assert!(best_response < equilibrium.value + TOLERANCE, "choice {} gets the player {}", i, best_response);
+    }
+}
+
+#[test]
+fn rock_paper_scissors_is_fair_and_uniform() {
+    let payoffs = vec![
+        vec![0.0, -1.0, 1.0],
+        vec![1.0, 0.0, -1.0],
+        vec![-1.0, 1.0, 0.0],
+    ];
+    let equilibrium = nash::solve(vec!["rock", "paper", "scissors"], vec!["rock", "paper", "scissors"], &payoffs);
+
+    assert_close(equilibrium.value, 0.0);
+    for probability in probabilities(&equilibrium.player_strategy).into_iter().chain(probabilities(&equilibrium.opponent_strategy)) {
+        assert_close(probability, 1.0 / 3.0);
+    }
+    assert_equilibrium(&equilibrium, &payoffs);
+}
+
+#[test]
+fn dominated_choices_are_never_chosen() {
+    // The first row dominates the second, and then the first column dominates the second.
+    let payoffs = vec![
+        vec![1.0, 3.0],
+        vec![0.0, 2.0],
+    ];
+    let equilibrium = nash::solve(vec![0, 1], vec![0, 1], &payoffs);
+
+    assert_close(equilibrium.value, 1.0);
+    assert_eq!(probabilities(&equilibrium.player_strategy), vec![1.0, 0.0]);
+    assert_eq!(probabilities(&equilibrium.opponent_strategy), vec![1.0, 0.0]);
+}
+
+#[test]
+fn solves_an_unfair_mixed_game() {
+    let payoffs = vec![
+        vec![2.0, -1.0],
+        vec![-1.0, 1.0],
+    ];
+    let equilibrium = nash::solve(vec![0, 1], vec![0, 1], &payoffs);
+
+    assert_close(equilibrium.value, 0.2);
+    assert_close(equilibrium.player_strategy[0].1, 0.4);
+    assert_close(equilibrium.opponent_strategy[0].1, 0.4);
+    assert_equilibrium(&equilibrium, &payoffs);
+}
+
+#[test]
+fn empty_games_have_no_strategies() {
+    let equilibrium = nash::solve(Vec::<u8>::new(), vec![0], &[]);
+
+    assert!(equilibrium.player_strategy.is_empty());
+    assert_close(equilibrium.value, 0.0);
+}
+
+fn player(character: Character, booster: Booster) -> Player {
+    CharacterlessPlayer::with_rules(Rules::standard())
+        .to_boosterless_player(character)
+        .to_player(booster)
+}
+
+#[test]
+fn solves_a_move_round() {
+    let human = player(Character::Ninja, Booster::Shadow);
+    let computer = player(Character::Zombie, Booster::Regenerative);
+    let payoffs: Vec<Vec<f64>> = human.available_moves().into_iter()
+        .map(|human_move| {
+            computer.available_moves().into_iter()
+                .map(|computer_move| {
+                    let points = outcomes::get_move_points(human_move, human.booster, computer_move, computer.booster);
+
+                    points.0 as f64 - points.1 as f64
+                })
+                .collect()
+        })
+        .collect();
+
+    assert_equilibrium(&nash::solve_moves(&human, &computer, &Rules::standard()), &payoffs);
+}
+
+#[test]
+fn boosters_are_solved_without_no_booster() {
+    let human = CharacterlessPlayer::with_rules(Rules::standard()).to_boosterless_player(Character::Samurai);
+    let computer = CharacterlessPlayer::with_rules(Rules::standard()).to_boosterless_player(Character::Clown);
+    let equilibrium = nash::solve_boosters(&human, &computer, &Rules::standard());
+
+    assert!(equilibrium.player_strategy.iter().all(|&(booster, _)| booster != Booster::None));
+    assert!(equilibrium.opponent_strategy.iter().all(|&(booster, _)| booster != Booster::None));
+    assert_close(probabilities(&equilibrium.player_strategy).iter().sum(), 1.0);
+}
@@ -0,0 +1,76 @@
+extern crate nzsc_single_player;
+
+use nzsc_single_player::single_player_game::SinglePlayerNZSCGame;
+use nzsc_single_player::two_player_game::TwoPlayerNZSCGame;
+use nzsc_single_player::rules::Rules;
+use nzsc_single_player::characters::Character;
+use nzsc_single_player::locale::Opponent;
+use nzsc_single_player::io::{
+    Answer,
+    CharacterSelection,
+    Notification,
+};
+
+fn pirate() -> Answer {
+    Answer::CharacterSelection(CharacterSelection::Nonexistent("Pirate".to_string()))
+}
+
+fn render(notifications: &[Notification]) -> Vec<String> {
+    notifications.iter().map(|n| n.to_string()).collect()
+}
+
+fn render_two_player(notifications: &[Notification], rules: &Rules) -> Vec<String> {
+    notifications.iter().map(|n| n.render_against(rules, Opponent::Human)).collect()
+}
+
+#[test]
+fn penalties_say_whether_waits_or_a_point_were_lost() {
+    let mut game = SinglePlayerNZSCGame::new(1, Rules::standard());
+
+    // The first penalty uses up all 4 waits.
+    let output = game.next(pirate()).unwrap();
+    assert_eq!(render(&output.notifications), vec![
+        "There's no character called \"Pirate\". That costs 4 waits.",
+        "Score: you 0, computer 0.",
+    ]);
+
+    let output = game.next(pirate()).unwrap();
+    assert_eq!(render(&output.notifications), vec![
+        "There's no character called \"Pirate\". That costs 4 waits.",
+        "You don't have enough waits left, so the computer gets a point instead.",
+        "Score: you 0, computer 1.",
+    ]);
+}
+
+#[test]
+fn opponents_penalties_say_whether_waits_or_a_point_were_lost() {
+    let rules = Rules { starting_waits: 3, ..Rules::standard() };
+    let mut game = TwoPlayerNZSCGame::new(rules);
+    let zombie = || Answer::CharacterSelection(CharacterSelection::Character(Character::Zombie));
+
+    let output = game.next(pirate(), zombie()).unwrap();
+    assert_eq!(render_two_player(&output.b.notifications, &rules), vec![
+        "Your opponent chose illegally. There's no character called \"Pirate\". That costs them 4 waits.",
+        "Your opponent doesn't have enough waits left, so you get a point instead.",
+        "Score: you 1, your opponent 0.",
+    ]);
+    assert_eq!(render_two_player(&output.a.notifications, &rules), vec![
+        "There's no character called \"Pirate\". That costs 4 waits.",
+        "You don't have enough waits left, so your opponent gets a point instead.",
+        "Score: you 0, your opponent 1.",
+    ]);
+}
+
+#[test]
+fn two_player_notifications_dont_mention_a_computer() {
+    let rules = Rules::standard();
+    let mut game = TwoPlayerNZSCGame::new(rules);
+    let ninja = Answer::CharacterSelection(CharacterSelection::Character(Character::Ninja));
+    let clown = Answer::CharacterSelection(CharacterSelection::Character(Character::Clown));
+
+    let output = game.next(ninja, clown).unwrap();
+    for line in render_two_player(&output.a.notifications, &rules).iter().chain(&render_two_player(&output.b.notifications, &rules)) {
+        assert!(!line.contains("computer"), "{}", line);
+    }
+    assert_eq!(render_two_player(&output.a.notifications, &rules)[0], "You chose Ninja, your opponent chose Clown. Your opponent gets a headstart.");
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "python")]
+
+extern crate nzsc_single_player;
+extern crate pyo3;
+
+use std::ffi::CString;
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+/// Runs each `test_*` function in `tests/python/test_smoke.py`, like pytest would.
+#[test]
+fn python_smoke_tests_pass() {
+    Python::initialize();
+
+    Python::attach(|py| {
+        let module = pyo3::wrap_pymodule!(nzsc_single_player::python::nzsc_single_player)(py);
+        py.import("sys").unwrap()
+            .getattr("modules").unwrap()
+            .set_item("nzsc_single_player", module).unwrap();
+
+        let globals = PyDict::new(py);
+        let source = CString::new(include_str!("python/test_smoke.py")).unwrap();
+        py.run(&source, Some(&globals), None).unwrap();
+
+        let mut ran = 0;
+        for (name, function) in globals.iter() {
+            let name: String = name.extract().unwrap();
+            if name.starts_with("test_") {
+                if let Err(error) = function.call0() {
+                    panic!("{} failed: {}", name, error);
+                }
+                ran += 1;
+            }
+        }
+        assert_eq!(ran, 5);
+    });
+}
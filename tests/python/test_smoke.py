@@ -0,0 +1,86 @@
+"""Smoke tests for the Python module.
+
+Run them with `maturin develop --features python,pyo3/extension-module && pytest tests/python`.
+`tests/python.rs` runs them too, with the module built into the test binary.
+"""
+
+from nzsc_single_player import (
+    Booster,
+    Character,
+    HistoryEntry,
+    Move,
+    Notification,
+    Question,
+    Rules,
+    SinglePlayerNZSCGame,
+    Strategy,
+    get_points,
+)
+
+
+def answer_first_option(game, question):
+    if isinstance(question, Question.ChooseCharacter):
+        return game.choose_character(question.available_characters[0])
+    if isinstance(question, Question.ChooseBooster):
+        return game.choose_booster(question.available_boosters[0])
+    if question.available_moves:
+        return game.choose_move(question.available_moves[0])
+    return game.answer_text("Nothing")
+
+
+def play_to_the_end(game):
+    output = game.current_output()
+    for _ in range(1000):
+        if output.question is None:
+            return output
+        output = answer_first_option(game, output.question)
+    raise AssertionError("The game didn't end")
+
+
+def test_names_parse():
+    assert Character.parse("ninja") == Character.Ninja
+    assert str(Move.parse("Ninja Sword")) == "Ninja Sword"
+    assert Booster.Shadow in Character.Ninja.get_boosters()
+    assert len(Move.all()) == 28
+    assert get_points([Move.Kick, Move.Kick]) == [0, 0]
+    assert sum(get_points(Move.all())) > 0
+
+
+def test_games_can_be_played_to_the_end():
+    for strategy in [Strategy.Random, Strategy.Hard, Strategy.Nash, Strategy.Adaptive]:
+        output = play_to_the_end(SinglePlayerNZSCGame(7, strategy=strategy))
+        assert isinstance(output.notifications[-1], Notification.GameOver)
+
+
+def test_games_use_their_rules():
+    rules = Rules(points_to_win=1, tiebreak_points=0)
+    assert rules.starting_waits == Rules.standard().starting_waits
+    game = SinglePlayerNZSCGame(3, rules)
+    assert game.rules == rules
+
+    game_over = play_to_the_end(game).notifications[-1]
+    assert max(game_over.human_points, game_over.computer_points) == 1
+
+
+def test_invalid_rules_are_rejected():
+    rules = Rules(points_to_win=0, tiebreak_points=0)
+    for attempt in [rules.validate, lambda: SinglePlayerNZSCGame(1, rules)]:
+        try:
+            attempt()
+        except ValueError:
+            continue
+        raise AssertionError("Expected a ValueError")
+
+
+def test_history_records_answers_and_choices():
+    game = SinglePlayerNZSCGame(5)
+    game.answer_text("Pirate")
+    game.choose_character(Character.Ninja)
+
+    history = game.history()
+    assert all(isinstance(entry, HistoryEntry) for entry in history)
+    assert history[0].answer == "Pirate"
+    assert history[0].computer_choice is None
+    assert isinstance(history[0].notifications[0], Notification.CharacterNonexistentPenalty)
+    assert history[1].answer == Character.Ninja
+    assert isinstance(history[1].computer_choice, Character)
@@ -0,0 +1,68 @@
+extern crate nzsc_single_player;
+
+use nzsc_single_player::single_player_game::{
+    SinglePlayerNZSCGame,
+    Whose,
+};
+use nzsc_single_player::rules::Rules;
+use nzsc_single_player::io::{
+    Answer,
+    CharacterSelection,
+    BoosterSelection,
+    MoveSelection,
+    Notification,
+    Question,
+};
+
+fn first_answer(question: Question) -> Answer {
+    match question {
+        Question::ChooseCharacter { available_characters } => {
+            Answer::CharacterSelection(CharacterSelection::Character(available_characters[0]))
+        },
+        Question::ChooseBooster { available_boosters } => {
+            Answer::BoosterSelection(BoosterSelection::Booster(available_boosters[0]))
+        },
+        Question::ChooseMove { available_moves } => match available_moves.first() {
+            Some(&first_move) => Answer::MoveSelection(MoveSelection::Move(first_move)),
+            None => Answer::MoveSelection(MoveSelection::Nonexistent(String::new())),
+        },
+    }
+}
+
+#[test]
+fn accessors_follow_the_game() {
+    let mut game = SinglePlayerNZSCGame::new(3, Rules::standard());
+
+    assert_eq!(game.points(Whose::Human), 0);
+    assert_eq!(game.waits(Whose::Human), Some(Rules::standard().starting_waits));
+    assert_eq!(game.character(Whose::Computer), None);
+    assert!(game.character_streak(Whose::Human).is_some());
+    assert!(!game.is_over());
+
+    for _ in 0..1000 {
+        let question = match game.current_question() {
+            Some(question) => question,
+            None => break,
+        };
+        let output = game.next(first_answer(question)).unwrap();
+
+        for notification in output.notifications {
+            if let Notification::ScoreUpdate { human_points, computer_points } = notification {
+                assert_eq!(game.points(Whose::Human), human_points);
+                assert_eq!(game.points(Whose::Computer), computer_points);
+            }
+        }
+        if game.booster(Whose::Human).is_some() {
+            assert!(game.character(Whose::Human).is_some());
+            assert!(game.move_streak(Whose::Computer).is_some());
+        }
+    }
+
+    assert!(game.is_over());
+    assert_eq!(game.waits(Whose::Human), None);
+    assert!(game.destroyed_moves(Whose::Human).is_empty());
+
+    let winner = game.winner().unwrap();
+    let loser = if winner == Whose::Human { Whose::Computer } else { Whose::Human };
+    assert!(game.points(winner) > game.points(loser));
+}
@@ -0,0 +1,97 @@
+extern crate nzsc_single_player;
+
+use nzsc_single_player::characters::Character;
+use nzsc_single_player::boosters::Booster;
+use nzsc_single_player::moves::Move;
+use nzsc_single_player::io::{
+    Answer,
+    Question,
+    CharacterSelection,
+    BoosterSelection,
+    MoveSelection,
+};
+
+fn choose_character() -> Question {
+    Question::ChooseCharacter {
+        available_characters: vec![Character::Ninja, Character::Zombie, Character::Samurai],
+    }
+}
+
+fn choose_booster() -> Question {
+    Question::ChooseBooster {
+        available_boosters: vec![Booster::Shadow, Booster::Speedy, Booster::None],
+    }
+}
+
+fn choose_move() -> Question {
+    Question::ChooseMove {
+        available_moves: vec![Move::Kick, Move::NinjaSword, Move::ShadowFireball, Move::ShadowSlip],
+    }
+}
+
+/// Describes an answer, since answers can't be compared.
+fn describe(answer: &Answer) -> String {
+    match *answer {
+        Answer::CharacterSelection(CharacterSelection::Character(character)) => format!("character {}", character),
+        Answer::CharacterSelection(CharacterSelection::Nonexistent(ref name)) => format!("nonexistent character {:?}", name),
+        Answer::BoosterSelection(BoosterSelection::Booster(booster)) => format!("booster {}", booster),
+        Answer::BoosterSelection(BoosterSelection::Nonexistent(ref name)) => format!("nonexistent booster {:?}", name),
+        Answer::MoveSelection(MoveSelection::Move(chosen_move)) => format!("move {}", chosen_move),
+        Answer::MoveSelection(MoveSelection::Nonexistent(ref name)) => format!("nonexistent move {:?}", name),
+    }
+}
+
+#[test]
+fn parses_names_ignoring_case_and_whitespace() {
+    assert_eq!(describe(&choose_character().parse_answer("  zOmBiE\n")), "character Zombie");
+    assert_eq!(describe(&choose_booster().parse_answer("Speedy")), "booster Speedy");
+    assert_eq!(describe(&choose_move().parse_answer("shadow  fireball")), "move Shadow Fireball");
+}
+
+#[test]
+fn parses_aliases() {
+    assert_eq!(describe(&choose_booster().parse_answer("none")), "booster No Booster");
+    assert_eq!(describe(&choose_booster().parse_answer("No Booster")), "booster No Booster");
+}
+
+#[test]
+fn parses_names_that_arent_available() {
+    // The game decides how to penalize these.
+    assert_eq!(describe(&choose_character().parse_answer("Clown")), "character Clown");
+    assert_eq!(describe(&choose_move().parse_answer("Zap")), "move Zap");
+}
+
+#[test]
+fn unknown_names_are_nonexistent_even_if_they_are_close() {
+    assert_eq!(describe(&choose_character().parse_answer(" ninjaa ")), "nonexistent character \"ninjaa\"");
+    assert_eq!(describe(&choose_booster().parse_answer("Zap")), "nonexistent booster \"Zap\"");
+    assert_eq!(describe(&choose_move().parse_answer("")), "nonexistent move \"\"");
+}
+
+#[test]
+fn suggests_only_available_answers() {
+    let suggestions = choose_character().suggestions("clwon");
+    assert!(suggestions.is_empty());
+
+    let suggestions = choose_move().suggestions("shadow");
+    let names: Vec<&str> = suggestions.iter().map(|s| &s.name[..]).collect();
+    assert_eq!(names, vec!["Shadow Fireball", "Shadow Slip"]);
+}
+
+#[test]
+fn did_you_mean_finds_typos_within_the_threshold() {
+    let suggestion = choose_character().did_you_mean("Ninjaa").expect("Ninja is close enough");
+    assert_eq!(suggestion.name, "Ninja");
+    assert_eq!(suggestion.score, 3);
+    assert_eq!(describe(&suggestion.answer), "character Ninja");
+
+    assert_eq!(describe(&choose_move().did_you_mean("ns").unwrap().answer), "move Ninja Sword");
+    assert_eq!(describe(&choose_booster().did_you_mean("nb").unwrap().answer), "booster No Booster");
+}
+
+#[test]
+fn did_you_mean_gives_up_beyond_the_threshold_or_on_ties() {
+    assert!(choose_character().did_you_mean("Nxxxxa").is_none());
+    assert!(choose_move().did_you_mean("xyz").is_none());
+    assert!(choose_move().did_you_mean("shadow").is_none());
+}
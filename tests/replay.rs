@@ -0,0 +1,147 @@
+extern crate nzsc_single_player;
+
+use nzsc_single_player::single_player_game::{
+    SinglePlayerNZSCGame,
+    Whose,
+};
+use nzsc_single_player::strategies::AdaptiveStrategy;
+use nzsc_single_player::history::{
+    ComputerChoice,
+    HistoryEntry,
+};
+use nzsc_single_player::rules::Rules;
+use nzsc_single_player::characters::Character;
+use nzsc_single_player::boosters::Booster;
+use nzsc_single_player::moves::Move;
+use nzsc_single_player::io::{
+    Answer,
+    CharacterSelection,
+    BoosterSelection,
+    MoveSelection,
+    Question,
+};
+
+/// Answers with the `turn`th available option, with an occasional nonexistent move to cause penalties.
+fn answer(question: &Question, turn: usize) -> Answer {
+    match *question {
+        Question::ChooseCharacter { ref available_characters } => Answer::CharacterSelection(
+            CharacterSelection::Character(available_characters[turn % available_characters.len()])
+        ),
+        Question::ChooseBooster { ref available_boosters } => Answer::BoosterSelection(
+            BoosterSelection::Booster(available_boosters[turn % available_boosters.len()])
+        ),
+        Question::ChooseMove { ref available_moves } => Answer::MoveSelection(
+            if available_moves.is_empty() || turn % 11 == 5 {
+                MoveSelection::Nonexistent("Nothing".to_string())
+            } else {
+                MoveSelection::Move(available_moves[turn % available_moves.len()])
+            }
+        ),
+    }
+}
+
+fn play(mut game: SinglePlayerNZSCGame) -> SinglePlayerNZSCGame {
+    for turn in 0..200 {
+        match game.current_question() {
+            Some(question) => {
+                game.next(answer(&question, turn)).unwrap();
+            },
+            None => break,
+        }
+    }
+
+    game
+}
+
+fn describe_entry(entry: &HistoryEntry) -> (Option<ComputerChoice>, Vec<String>) {
+    (entry.computer_choice, entry.notifications.iter().map(|notification| notification.to_string()).collect())
+}
+
+/// Everything the accessors show about the game.
+fn describe_state(game: &SinglePlayerNZSCGame) -> String {
+    let mut description = format!("{:?}", game.winner());
+
+    for &whose in &[Whose::Human, Whose::Computer] {
+        description += &format!(
+            " | {} {:?} {:?} {:?} {:?}",
+            game.points(whose),
+            game.waits(whose),
+            game.character(whose),
+            game.booster(whose),
+            game.destroyed_moves(whose)
+        );
+    }
+
+    description
+}
+
+fn assert_replays_identically(original: &SinglePlayerNZSCGame, replayed: &SinglePlayerNZSCGame) {
+    assert_eq!(original.history().len(), replayed.history().len());
+    for (original_entry, replayed_entry) in original.history().iter().zip(replayed.history()) {
+        assert_eq!(describe_entry(original_entry), describe_entry(replayed_entry));
+    }
+    assert_eq!(describe_state(original), describe_state(replayed));
+}
+
+fn answers(game: &SinglePlayerNZSCGame) -> Vec<Answer> {
+    game.history().iter().map(|entry| entry.answer.clone()).collect()
+}
+
+#[test]
+fn recorded_games_replay_identically() {
+    for seed in 1..30 {
+        let original = play(SinglePlayerNZSCGame::new(seed, Rules::standard()));
+        assert!(original.is_over());
+
+        let replayed = SinglePlayerNZSCGame::replay(seed, Rules::standard(), answers(&original)).unwrap();
+        assert_replays_identically(&original, &replayed);
+    }
+}
+
+#[test]
+fn recorded_games_against_strategies_that_learn_replay_identically() {
+    for seed in 1..10 {
+        let original = play(SinglePlayerNZSCGame::with_strategy(seed, Rules::standard(), Box::new(AdaptiveStrategy::new())));
+        let replayed = SinglePlayerNZSCGame::replay_with_strategy(
+            seed,
+            Rules::standard(),
+            Box::new(AdaptiveStrategy::new()),
+            answers(&original),
+        ).unwrap();
+
+        assert_replays_identically(&original, &replayed);
+    }
+}
+
+/// A match recorded with seed 2018. If this fails, the rules, the outcome tables,
+/// or the random number generator changed, and old recordings won't replay the same way anymore.
+#[test]
+fn a_recorded_match_still_plays_out_the_same() {
+    let mut answers = vec![
+        Answer::CharacterSelection(CharacterSelection::Character(Character::Ninja)),
+        Answer::CharacterSelection(CharacterSelection::Character(Character::Zombie)),
+        Answer::BoosterSelection(BoosterSelection::Booster(Booster::None)),
+    ];
+    answers.extend(
+        [Move::Rampage, Move::Muscle, Move::Zap, Move::Rampage, Move::Muscle, Move::Rampage, Move::Muscle, Move::Rampage].iter()
+            .map(|&chosen_move| Answer::MoveSelection(MoveSelection::Move(chosen_move)))
+    );
+    let game = SinglePlayerNZSCGame::replay(2018, Rules::standard(), answers).unwrap();
+
+    let mut expected_choices = vec![
+        ComputerChoice::Character(Character::Ninja),
+        ComputerChoice::Character(Character::Samurai),
+        ComputerChoice::Booster(Booster::Strong),
+    ];
+    expected_choices.extend(
+        [Move::Helmet, Move::Twist, Move::SamuraiSword, Move::Smash, Move::Twist, Move::Bend, Move::Helmet, Move::Smash].iter()
+            .map(|&chosen_move| ComputerChoice::Move(chosen_move))
+    );
+    let computer_choices: Vec<ComputerChoice> = game.history().iter()
+        .map(|entry| entry.computer_choice.unwrap())
+        .collect();
+
+    assert_eq!(computer_choices, expected_choices);
+    assert_eq!(game.winner(), Some(Whose::Computer));
+    assert_eq!((game.points(Whose::Human), game.points(Whose::Computer)), (0, 5));
+}
@@ -0,0 +1,96 @@
+extern crate nzsc_single_player;
+
+use nzsc_single_player::single_player_game::{
+    SinglePlayerNZSCGame,
+    Whose,
+};
+use nzsc_single_player::rules::Rules;
+use nzsc_single_player::errors::RulesError;
+use nzsc_single_player::players::CharacterlessPlayer;
+use nzsc_single_player::characters::Character;
+use nzsc_single_player::boosters::Booster;
+use nzsc_single_player::moves::Move;
+use nzsc_single_player::io::{
+    Answer,
+    CharacterSelection,
+    BoosterSelection,
+    MoveSelection,
+    Question,
+};
+
+#[test]
+fn the_standard_rules_are_valid() {
+    assert_eq!(Rules::standard().validate(), Ok(()));
+    assert_eq!(Rules { points_to_win: 1, tiebreak_points: 0, ..Rules::standard() }.validate(), Ok(()));
+}
+
+#[test]
+fn games_must_need_points_to_win() {
+    let rules = Rules { points_to_win: 0, tiebreak_points: 0, ..Rules::standard() };
+
+    assert_eq!(rules.validate(), Err(RulesError::NoPointsToWin));
+}
+
+#[test]
+fn ties_must_be_broken_below_the_points_to_win() {
+    for &tiebreak_points in &[5, 6] {
+        let rules = Rules { tiebreak_points, ..Rules::standard() };
+
+        assert_eq!(rules.validate(), Err(RulesError::TiebreakPointsTooHigh {
+            tiebreak_points,
+            points_to_win: 5,
+        }));
+    }
+}
+
+#[test]
+fn streak_limits_come_from_the_rules_passed_in() {
+    let mut player = CharacterlessPlayer::new().to_boosterless_player(Character::Ninja).to_player(Booster::Shadow);
+    player.move_streak.add(Move::Kick);
+    let strict = Rules { move_streak_limit: 1, ..Rules::standard() };
+
+    assert!(player.available_moves().contains(&Move::Kick));
+    assert!(player.available_moves_with_rules(&Rules::standard()).contains(&Move::Kick));
+    assert!(!player.available_moves_with_rules(&strict).contains(&Move::Kick));
+}
+
+#[test]
+fn games_use_their_rules() {
+    let rules = Rules { starting_waits: 2, move_streak_limit: 1, ..Rules::standard() };
+    let mut game = SinglePlayerNZSCGame::new(3, rules);
+    assert_eq!(game.rules(), rules);
+
+    let mut character = Character::Ninja;
+    while game.character(Whose::Human).is_none() {
+        game.next(Answer::CharacterSelection(CharacterSelection::Character(character))).unwrap();
+        character = Character::Zombie;
+    }
+    assert_eq!(game.waits(Whose::Human), Some(2));
+
+    let booster = game.character(Whose::Human).unwrap().get_boosters()[0];
+    let output = game.next(Answer::BoosterSelection(BoosterSelection::Booster(booster))).unwrap();
+    let first_move = match output.question {
+        Some(Question::ChooseMove { ref available_moves }) => available_moves[0],
+        _ => panic!("Expected a move question"),
+    };
+
+    let output = game.next(Answer::MoveSelection(MoveSelection::Move(first_move))).unwrap();
+    match output.question {
+        Some(Question::ChooseMove { ref available_moves }) => assert!(!available_moves.contains(&first_move)),
+        _ => panic!("Expected a move question"),
+    }
+}
+
+#[test]
+fn a_headstart_can_win_the_game() {
+    let rules = Rules { points_to_win: 1, tiebreak_points: 0, ..Rules::standard() };
+    let mut game = SinglePlayerNZSCGame::new(0, rules);
+
+    // With this seed, the computer chooses a character Clown gets a headstart against.
+    let output = game.next(Answer::CharacterSelection(CharacterSelection::Character(Character::Clown))).unwrap();
+
+    assert_eq!(game.points(Whose::Human), 1);
+    assert!(game.is_over());
+    assert_eq!(game.winner(), Some(Whose::Human));
+    assert!(output.question.is_none());
+}
@@ -0,0 +1,268 @@
+#![cfg(feature = "ruleset")]
+
+extern crate nzsc_single_player;
+
+use nzsc_single_player::ruleset::{
+    Ruleset,
+    RulesetError,
+    CustomNZSCGame,
+    Phase,
+    Question,
+    Notification,
+    MoveDefinition,
+    BoosterDefinition,
+    CharacterDefinition,
+    BoosterOutcome,
+};
+use nzsc_single_player::rules::Rules;
+use nzsc_single_player::errors::RulesError;
+
+fn move_definition(name: &str, single_use: bool) -> MoveDefinition {
+    MoveDefinition {
+        name: name.to_string(),
+        aliases: vec![],
+        single_use,
+        destructive: false,
+    }
+}
+
+fn character_definition(name: &str, moves: &[&str], boosters: &[&str]) -> CharacterDefinition {
+    CharacterDefinition {
+        name: name.to_string(),
+        aliases: vec![],
+        moves: moves.iter().map(|m| m.to_string()).collect(),
+        boosters: boosters.iter().map(|b| b.to_string()).collect(),
+    }
+}
+
+/// Alpha can only play Once (a single-use move), and Beta can play Again as often as it likes.
+fn tiny_ruleset() -> Ruleset {
+    Ruleset {
+        moves: vec![
+            move_definition("Once", true),
+            move_definition("Again", false),
+        ],
+        boosters: vec![
+            BoosterDefinition {
+                name: "Plain".to_string(),
+                aliases: vec![],
+                moves: vec![],
+            },
+        ],
+        characters: vec![
+            character_definition("Alpha", &["Once"], &["Plain"]),
+            character_definition("Beta", &["Again"], &["Plain"]),
+        ],
+        move_points: vec![],
+        headstarts: vec![],
+        booster_outcomes: vec![],
+    }
+}
+
+fn question(game: &CustomNZSCGame) -> Question {
+    game.current_output().question.expect("The game isn't over")
+}
+
+#[test]
+fn the_standard_ruleset_round_trips_through_json() {
+    let standard = Ruleset::standard();
+
+    assert_eq!(Ruleset::from_json(&standard.to_json()).unwrap(), standard);
+    assert!(standard.validate().is_ok());
+}
+
+#[test]
+fn invalid_json_is_a_parse_error() {
+    match Ruleset::from_json("{\"moves\": 3}") {
+        Err(RulesetError::Parse(_)) => {},
+        other => panic!("Expected a parse error, got {:?}", other),
+    }
+}
+
+#[test]
+fn names_must_be_unique_ignoring_case_and_whitespace() {
+    let mut ruleset = tiny_ruleset();
+    ruleset.moves[1].aliases.push(" o N c E".to_string());
+
+    match ruleset.validate() {
+        Err(RulesetError::DuplicateName(ref name)) => assert_eq!(name, " o N c E"),
+        other => panic!("Expected a duplicate name, got {:?}", other),
+    }
+}
+
+#[test]
+fn names_must_refer_to_something() {
+    let mut unknown_move = tiny_ruleset();
+    unknown_move.characters[0].moves.push("Twice".to_string());
+    let mut unknown_booster = tiny_ruleset();
+    unknown_booster.characters[1].boosters.push("Fancy".to_string());
+    let mut unknown_character = tiny_ruleset();
+    unknown_character.headstarts.push(["Alpha".to_string(), "Gamma".to_string()]);
+
+    for &(ref ruleset, expected_kind, expected_name) in &[
+        (unknown_move, "move", "Twice"),
+        (unknown_booster, "booster", "Fancy"),
+        (unknown_character, "character", "Gamma"),
+    ] {
+        match ruleset.validate() {
+            Err(RulesetError::UnknownName { kind, ref name }) => {
+                assert_eq!(kind, expected_kind);
+                assert_eq!(name, expected_name);
+            },
+            other => panic!("Expected an unknown {}, got {:?}", expected_kind, other),
+        }
+    }
+}
+
+#[test]
+fn there_must_be_two_characters() {
+    let mut ruleset = tiny_ruleset();
+    ruleset.characters.pop();
+
+    match ruleset.validate() {
+        Err(RulesetError::TooFewCharacters) => {},
+        other => panic!("Expected too few characters, got {:?}", other),
+    }
+}
+
+#[test]
+fn every_character_needs_a_booster() {
+    let mut ruleset = tiny_ruleset();
+    ruleset.characters[1].boosters.clear();
+
+    match ruleset.validate() {
+        Err(RulesetError::NoBoosters(ref name)) => assert_eq!(name, "Beta"),
+        other => panic!("Expected no boosters, got {:?}", other),
+    }
+}
+
+#[test]
+fn every_character_needs_moves_with_every_booster() {
+    let mut ruleset = tiny_ruleset();
+    ruleset.characters[0].moves.clear();
+
+    match ruleset.validate() {
+        Err(RulesetError::NoMoves { ref character, ref booster }) => {
+            assert_eq!(character, "Alpha");
+            assert_eq!(booster, "Plain");
+        },
+        other => panic!("Expected no moves, got {:?}", other),
+    }
+
+    // Booster moves are enough.
+    ruleset.boosters[0].moves.push("Again".to_string());
+    assert!(ruleset.validate().is_ok());
+}
+
+#[test]
+fn booster_outcomes_give_at_most_one_point() {
+    let mut ruleset = tiny_ruleset();
+    ruleset.booster_outcomes.push(BoosterOutcome {
+        booster: "Plain".to_string(),
+        move_name: "Once".to_string(),
+        against: "Again".to_string(),
+        points: [2, 0],
+    });
+
+    match ruleset.validate() {
+        Err(RulesetError::TooManyPoints) => {},
+        other => panic!("Expected too many points, got {:?}", other),
+    }
+}
+
+#[test]
+fn games_reject_invalid_rules() {
+    let rules = Rules { points_to_win: 0, tiebreak_points: 0, ..Rules::standard() };
+
+    match CustomNZSCGame::new(1, rules, tiny_ruleset()) {
+        Err(RulesetError::InvalidRules(RulesError::NoPointsToWin)) => {},
+        Err(other) => panic!("Expected invalid rules, got {:?}", other),
+        Ok(_) => panic!("Expected invalid rules"),
+    }
+}
+
+#[test]
+fn a_standard_game_can_be_played_to_completion() {
+    let mut game = CustomNZSCGame::new(7, Rules::standard(), Ruleset::standard()).unwrap();
+
+    for turn in 0..1000 {
+        if let Phase::GameOver { human_points, computer_points } = game.phase {
+            assert!(human_points >= 5 || computer_points >= 5);
+            assert_ne!(human_points, computer_points);
+            return;
+        }
+
+        let answer = match question(&game) {
+            Question::ChooseCharacter { available_characters: options }
+            | Question::ChooseBooster { available_boosters: options }
+            | Question::ChooseMove { available_moves: options } => {
+                // Vary the choice, so the same character isn't chosen forever.
+                options.get(turn % options.len().max(1)).cloned().unwrap_or_default()
+            },
+        };
+
+        game.next(&answer).unwrap();
+    }
+
+    panic!("The game didn't end");
+}
+
+#[test]
+fn the_computer_is_penalized_when_it_has_no_moves_left() {
+    // Find a game where the computer chooses Alpha, so it only has one move.
+    let mut game = (1..100)
+        .map(|seed| {
+            let mut game = CustomNZSCGame::new(seed, Rules::standard(), tiny_ruleset()).unwrap();
+            game.next("Beta").unwrap();
+            game
+        })
+        .find(|game| matches!(game.phase, Phase::BoosterChoosing { .. }))
+        .expect("The computer chooses Alpha in some game");
+    game.next("Plain").unwrap();
+
+    let output = game.next("Again").unwrap();
+    match output.notifications[0] {
+        Notification::MoveSelectionAndOutcome { ref computer_move, .. } => assert_eq!(computer_move, "Once"),
+        _ => panic!("Expected a move outcome"),
+    }
+
+    // The computer pays with its waits first, then with points.
+    let output = game.next("Again").unwrap();
+    match output.notifications[0] {
+        Notification::ComputerPenalty { waits } => assert_eq!(waits, 4),
+        _ => panic!("Expected the computer to be penalized"),
+    }
+
+    for _ in 0..5 {
+        game.next("Again").unwrap();
+    }
+    match game.phase {
+        Phase::GameOver { human_points, computer_points } => assert_eq!((human_points, computer_points), (5, 0)),
+        _ => panic!("Expected the human to win"),
+    }
+}
+
+#[test]
+fn a_headstart_can_win_the_game() {
+    let mut ruleset = tiny_ruleset();
+    ruleset.headstarts.push(["Alpha".to_string(), "Beta".to_string()]);
+    let rules = Rules { points_to_win: 1, tiebreak_points: 0, ..Rules::standard() };
+
+    // Find a game where the computer chooses Beta, so Alpha gets a headstart.
+    // (The generator's first number is tiny for small seeds, so spread them out.)
+    let game = (1..100)
+        .map(|n| n * 0x0123_4567)
+        .map(|seed| {
+            let mut game = CustomNZSCGame::new(seed, rules, ruleset.clone()).unwrap();
+            game.next("Alpha").unwrap();
+            game
+        })
+        .find(|game| !matches!(game.phase, Phase::CharacterChoosing { .. }))
+        .expect("The computer chooses Beta in some game");
+
+    match game.phase {
+        Phase::GameOver { human_points, computer_points } => assert_eq!((human_points, computer_points), (1, 0)),
+        _ => panic!("Expected the headstart to win the game"),
+    }
+    assert!(game.current_output().question.is_none());
+}
@@ -0,0 +1,235 @@
+#![cfg(feature = "serde")]
+
+extern crate nzsc_single_player;
+extern crate serde_json;
+
+use nzsc_single_player::io::{
+    Answer,
+    CharacterSelection,
+    BoosterSelection,
+    MoveSelection,
+    Output,
+    Question,
+};
+use nzsc_single_player::prfg::PseudorandomFloatGenerator;
+use nzsc_single_player::strategies::{
+    ComputerStrategy,
+    RandomStrategy,
+    HardStrategy,
+    NashStrategy,
+    AdaptiveStrategy,
+    Script,
+    ScriptedStrategy,
+};
+use nzsc_single_player::players::{
+    CharacterlessPlayer,
+    BoosterlessPlayer,
+    Player,
+};
+use nzsc_single_player::history::HistoryEntry;
+use nzsc_single_player::characters::Character;
+use nzsc_single_player::boosters::Booster;
+use nzsc_single_player::moves::Move;
+use nzsc_single_player::rules::Rules;
+use nzsc_single_player::single_player_game::SinglePlayerNZSCGame;
+
+/// Answers with the `turn`th available option, so different turns get different answers.
+fn answer(output: &Output, turn: usize) -> Option<Answer> {
+    output.question.as_ref().map(|question| {
+        match *question {
+            Question::ChooseCharacter { ref available_characters } => Answer::CharacterSelection(
+                CharacterSelection::Character(available_characters[turn % available_characters.len()])
+            ),
+            Question::ChooseBooster { ref available_boosters } => Answer::BoosterSelection(
+                BoosterSelection::Booster(available_boosters[turn % available_boosters.len()])
+            ),
+            Question::ChooseMove { ref available_moves } => Answer::MoveSelection(
+                if available_moves.is_empty() {
+                    MoveSelection::Nonexistent("Nothing".to_string())
+                } else {
+                    MoveSelection::Move(available_moves[turn % available_moves.len()])
+                }
+            ),
+        }
+    })
+}
+
+#[test]
+fn prfg_round_trip_continues_sequence() {
+    let mut prfg = PseudorandomFloatGenerator::new(42);
+    prfg.next();
+
+    let json = serde_json::to_string(&prfg).unwrap();
+    let mut restored: PseudorandomFloatGenerator = serde_json::from_str(&json).unwrap();
+
+    for _ in 0..10 {
+        assert_eq!(prfg.next(), restored.next());
+    }
+}
+
+/// Plays a few turns, saves and restores the game, and checks that both copies continue identically.
+fn assert_resumes_exactly<F: Fn() -> Box<dyn ComputerStrategy>>(new_strategy: F) {
+    for seed in 1..20 {
+        let mut game = SinglePlayerNZSCGame::with_strategy(seed, Rules::standard(), new_strategy());
+        let mut output = game.current_output();
+
+        for turn in 0..4 {
+            if let Some(answer) = answer(&output, turn) {
+                output = game.next(answer).unwrap();
+            }
+        }
+
+        let json = serde_json::to_string(&game).unwrap();
+        let mut restored: SinglePlayerNZSCGame = serde_json::from_str(&json).unwrap();
+        assert_eq!(json, serde_json::to_string(&restored).unwrap());
+
+        let mut restored_output = Output {
+            question: output.question.clone(),
+            notifications: vec![],
+        };
+        for turn in 4..40 {
+            match (answer(&output, turn), answer(&restored_output, turn)) {
+                (Some(original_answer), Some(restored_answer)) => {
+                    output = game.next(original_answer).unwrap();
+                    restored_output = restored.next(restored_answer).unwrap();
+                },
+                (None, None) => break,
+                _ => panic!("Restored game diverged at turn {}!", turn),
+            }
+
+            assert_eq!(serde_json::to_string(&game).unwrap(), serde_json::to_string(&restored).unwrap());
+        }
+    }
+}
+
+#[test]
+fn game_round_trip_resumes_exactly() {
+    assert_resumes_exactly(|| Box::new(RandomStrategy));
+}
+
+#[test]
+fn games_against_strategies_that_learn_resume_exactly() {
+    assert_resumes_exactly(|| Box::new(AdaptiveStrategy::new()));
+    assert_resumes_exactly(|| Box::new(HardStrategy::with_depth(2)));
+    assert_resumes_exactly(|| Box::new(ScriptedStrategy::new(Script {
+        characters: vec![Character::Clown],
+        boosters: vec![],
+        moves: vec![Move::Kick, Move::JugglingKnives, Move::AcidSpray],
+    })));
+}
+
+#[test]
+fn undo_restores_strategies_that_learn() {
+    let mut game = SinglePlayerNZSCGame::with_strategy(3, Rules::standard(), Box::new(AdaptiveStrategy::new()));
+    let mut output = game.current_output();
+    let mut saved = vec![];
+
+    for turn in 0..12 {
+        match answer(&output, turn) {
+            Some(answer) => {
+                saved.push(serde_json::to_string(&game).unwrap());
+                output = game.next(answer).unwrap();
+            },
+            None => break,
+        }
+    }
+
+    while let Some(expected) = saved.pop() {
+        game.undo().unwrap();
+        assert_eq!(serde_json::to_string(&game).unwrap(), expected);
+    }
+}
+
+#[test]
+fn games_with_undo_disabled_keep_no_undo_stack() {
+    let mut game = SinglePlayerNZSCGame::with_strategy(3, Rules::standard(), Box::new(AdaptiveStrategy::new()));
+    game.disable_undo();
+    let mut output = game.current_output();
+
+    for turn in 0..12 {
+        match answer(&output, turn) {
+            Some(answer) => output = game.next(answer).unwrap(),
+            None => break,
+        }
+    }
+
+    let json = serde_json::to_value(&game).unwrap();
+    assert_eq!(json["undo_stack"], serde_json::json!([]));
+}
+
+/// Chooses like `RandomStrategy`, but can't be saved.
+struct UnsavableStrategy;
+
+impl ComputerStrategy for UnsavableStrategy {
+    fn choose_character(
+        &mut self,
+        player: &CharacterlessPlayer,
+        opponent: &CharacterlessPlayer,
+        rules: &Rules,
+        history: &[HistoryEntry],
+        prfg: &mut PseudorandomFloatGenerator,
+    ) -> Character {
+        RandomStrategy.choose_character(player, opponent, rules, history, prfg)
+    }
+
+    fn choose_booster(
+        &mut self,
+        player: &BoosterlessPlayer,
+        opponent: &BoosterlessPlayer,
+        rules: &Rules,
+        history: &[HistoryEntry],
+        prfg: &mut PseudorandomFloatGenerator,
+    ) -> Booster {
+        RandomStrategy.choose_booster(player, opponent, rules, history, prfg)
+    }
+
+    fn choose_move(
+        &mut self,
+        player: &Player,
+        opponent: &Player,
+        rules: &Rules,
+        history: &[HistoryEntry],
+        prfg: &mut PseudorandomFloatGenerator,
+    ) -> Move {
+        RandomStrategy.choose_move(player, opponent, rules, history, prfg)
+    }
+}
+
+#[test]
+fn games_with_unsavable_strategies_fail_to_serialize() {
+    let game = SinglePlayerNZSCGame::with_strategy(1, Rules::standard(), Box::new(UnsavableStrategy));
+
+    assert!(serde_json::to_string(&game).is_err());
+}
+
+#[test]
+fn games_saved_without_a_strategy_use_random_strategy() {
+    let game = SinglePlayerNZSCGame::with_strategy(1, Rules::standard(), Box::new(NashStrategy));
+    let mut json: serde_json::Value = serde_json::to_value(&game).unwrap();
+    json.as_object_mut().unwrap().remove("strategy").unwrap();
+
+    let restored: SinglePlayerNZSCGame = serde_json::from_value(json).unwrap();
+    let expected = serde_json::to_value(SinglePlayerNZSCGame::new(1, Rules::standard())).unwrap();
+    assert_eq!(serde_json::to_value(&restored).unwrap(), expected);
+}
+
+#[test]
+fn games_saved_by_older_versions_can_be_resumed() {
+    // Older versions only saved the generator and the phase.
+    let json = r#"{
+        "prfg": {"state": 1892583},
+        "phase": {"CharacterChoosing": {
+            "human": {"points": 0, "waits": 4, "character_streak": {"repeated_character": "Ninja", "times": 1}},
+            "computer": {"points": 0, "waits": 4, "character_streak": {"repeated_character": "Ninja", "times": 1}}
+        }}
+    }"#;
+
+    let mut game: SinglePlayerNZSCGame = serde_json::from_str(json).unwrap();
+    assert_eq!(game.seed(), 0);
+    assert_eq!(game.rules(), Rules::standard());
+    assert!(game.history().is_empty());
+
+    game.next(Answer::CharacterSelection(CharacterSelection::Character(Character::Zombie))).unwrap();
+    assert_eq!(game.history().len(), 1);
+    assert!(serde_json::to_string(&game).is_ok());
+}
@@ -0,0 +1,172 @@
+#![cfg(feature = "server")]
+
+extern crate nzsc_single_player;
+extern crate serde_json;
+
+use std::io::{
+    BufRead,
+    BufReader,
+    Write,
+};
+use std::net::{
+    TcpListener,
+    TcpStream,
+};
+#[cfg(unix)]
+use std::os::unix::net::{
+    UnixListener,
+    UnixStream,
+};
+#[cfg(unix)]
+use std::env;
+#[cfg(unix)]
+use std::fs;
+use std::thread;
+use std::time::Duration;
+
+use serde_json::Value;
+
+use nzsc_single_player::server::{
+    self,
+    Server,
+};
+
+fn request(server: &mut Server, line: &str) -> Value {
+    serde_json::from_str(&server.handle_line(line)).unwrap()
+}
+
+#[test]
+fn hosts_independent_sessions() {
+    let mut server = Server::default();
+
+    let a = request(&mut server, r#"{"session": "a", "new": {"seed": 42}}"#);
+    assert!(a["output"]["question"]["ChooseCharacter"].is_object());
+    request(&mut server, r#"{"session": "b", "new": {"seed": 7}}"#);
+
+    let a = request(&mut server, r#"{"session": "a", "answer": {"CharacterSelection": {"Character": "Ninja"}}}"#);
+    assert_eq!(a["session"], "a");
+    assert!(!a["output"]["notifications"].as_array().unwrap().is_empty());
+
+    let b = request(&mut server, r#"{"session": "b", "text": "zombie"}"#);
+    assert!(b["output"].is_object());
+
+    let closed = request(&mut server, r#"{"session": "a", "close": {}}"#);
+    assert!(closed["closed"].is_object());
+    assert_eq!(server.session_ids(), vec!["b".to_string()]);
+}
+
+#[test]
+fn reports_errors_without_changing_sessions() {
+    let mut server = Server::default();
+
+    let missing = request(&mut server, r#"{"answer": {"CharacterSelection": {"Character": "Ninja"}}}"#);
+    assert_eq!(missing["session"], "default");
+    assert_eq!(missing["error"], "There is no session \"default\".");
+
+    request(&mut server, r#"{"new": {"seed": 42}}"#);
+    let duplicate = request(&mut server, r#"{"new": {"seed": 42}}"#);
+    assert_eq!(duplicate["error"], "The session \"default\" already exists.");
+
+    let wrong = request(&mut server, r#"{"answer": {"MoveSelection": {"Move": "Kick"}}}"#);
+    assert_eq!(wrong["error"], "Expected a character selection, but received a move selection.");
+
+    let invalid = request(&mut server, "not json");
+    assert!(invalid["session"].is_null());
+    assert!(invalid["error"].as_str().unwrap().starts_with("Invalid request"));
+}
+
+#[test]
+fn limits_the_number_of_sessions() {
+    let mut server = Server::default().with_max_sessions(2);
+    request(&mut server, r#"{"session": "a", "new": {"seed": 1}}"#);
+    request(&mut server, r#"{"session": "b", "new": {"seed": 2}}"#);
+
+    let full = request(&mut server, r#"{"session": "c", "new": {"seed": 3}}"#);
+    assert_eq!(full["error"], "The server already hosts 2 sessions. Close one first.");
+
+    request(&mut server, r#"{"session": "a", "close": {}}"#);
+    let replacement = request(&mut server, r#"{"session": "c", "new": {"seed": 3}}"#);
+    assert!(replacement["output"].is_object());
+}
+
+#[test]
+fn closes_idle_sessions() {
+    let mut server = Server::default().with_idle_timeout(Duration::from_millis(0));
+    request(&mut server, r#"{"new": {"seed": 42}}"#);
+
+    let expired = request(&mut server, r#"{"output": {}}"#);
+    assert_eq!(expired["error"], "There is no session \"default\".");
+    assert!(server.session_ids().is_empty());
+
+    let mut server = Server::default().with_idle_timeout(Duration::from_secs(60));
+    request(&mut server, r#"{"new": {"seed": 42}}"#);
+    assert!(request(&mut server, r#"{"output": {}}"#)["output"].is_object());
+}
+
+#[test]
+fn serves_concurrent_tcp_connections() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    thread::spawn(move || server::serve_tcp(&mut Server::default(), listener));
+
+    let clients: Vec<_> = (0..4).map(|i| {
+        thread::spawn(move || {
+            let mut stream = TcpStream::connect(address).unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut line = String::new();
+
+            writeln!(stream, r#"{{"session": "{}", "new": {{"seed": {}}}}}"#, i, i + 1).unwrap();
+            reader.read_line(&mut line).unwrap();
+            line.clear();
+            writeln!(stream, r#"{{"session": "{}", "text": "samurai"}}"#, i).unwrap();
+            reader.read_line(&mut line).unwrap();
+
+            let response: Value = serde_json::from_str(&line).unwrap();
+            assert_eq!(response["session"], i.to_string());
+            assert!(response["output"].is_object());
+        })
+    }).collect();
+
+    for client in clients {
+        client.join().unwrap();
+    }
+}
+
+#[test]
+fn closes_connections_that_send_overlong_lines() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    thread::spawn(move || server::serve_tcp(&mut Server::default(), listener));
+
+    let mut stream = TcpStream::connect(address).unwrap();
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+    let mut line = String::new();
+
+    stream.write_all(&vec![b' '; server::MAX_REQUEST_BYTES + 1]).unwrap();
+    reader.read_line(&mut line).unwrap();
+    let response: Value = serde_json::from_str(&line).unwrap();
+    assert!(response["error"].as_str().unwrap().contains("longer than"));
+
+    line.clear();
+    assert_eq!(reader.read_line(&mut line).unwrap(), 0);
+}
+
+#[cfg(unix)]
+#[test]
+fn serves_unix_socket_connections() {
+    let path = env::temp_dir().join(format!("nzsc-server-test-{}.sock", std::process::id()));
+    let _ = fs::remove_file(&path);
+    let listener = UnixListener::bind(&path).unwrap();
+    thread::spawn(move || server::serve_unix(&mut Server::default(), listener));
+
+    let mut stream = UnixStream::connect(&path).unwrap();
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+    let mut line = String::new();
+
+    writeln!(stream, r#"{{"new": {{"seed": 42}}}}"#).unwrap();
+    reader.read_line(&mut line).unwrap();
+    let response: Value = serde_json::from_str(&line).unwrap();
+    assert!(response["output"]["question"]["ChooseCharacter"].is_object());
+
+    fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,48 @@
+extern crate nzsc_single_player;
+
+use nzsc_single_player::simulation::{
+    Simulation,
+    Side,
+};
+use nzsc_single_player::strategies::{
+    ComputerStrategy,
+    RandomStrategy,
+    HardStrategy,
+};
+
+fn random() -> Box<dyn ComputerStrategy> {
+    Box::new(RandomStrategy)
+}
+
+fn hard() -> Box<dyn ComputerStrategy> {
+    Box::new(HardStrategy::new())
+}
+
+#[test]
+fn simulations_are_reproducible() {
+    let simulation = Simulation {
+        games: 200,
+        ..Simulation::default()
+    };
+    let report = simulation.run(random, random);
+
+    assert_eq!(report, simulation.run(random, random));
+    assert_eq!(report.games.len(), 200);
+    assert_eq!(report.wins(Side::A) + report.wins(Side::B) + report.unfinished(), 200);
+    assert!(report.average_rounds() > 0.0);
+    assert!(report.headstart_games() > 0);
+    // Strategies only choose legal options.
+    assert_eq!(report.penalties(Side::A).three_times_in_a_row, 0);
+    assert_eq!(report.penalties(Side::B).wrong_character, 0);
+}
+
+#[test]
+fn hard_beats_random() {
+    let simulation = Simulation {
+        games: 50,
+        ..Simulation::default()
+    };
+    let report = simulation.run(hard, random);
+
+    assert!(report.win_rate(Side::A) > report.win_rate(Side::B), "{}", report);
+}
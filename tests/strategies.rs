@@ -0,0 +1,117 @@
+extern crate nzsc_single_player;
+
+use std::sync::{
+    Arc,
+    Mutex,
+};
+
+use nzsc_single_player::single_player_game::SinglePlayerNZSCGame;
+use nzsc_single_player::strategies::ComputerStrategy;
+use nzsc_single_player::players::{
+    CharacterlessPlayer,
+    BoosterlessPlayer,
+    Player,
+};
+use nzsc_single_player::history::HistoryEntry;
+use nzsc_single_player::prfg::PseudorandomFloatGenerator;
+use nzsc_single_player::rules::Rules;
+use nzsc_single_player::characters::Character;
+use nzsc_single_player::boosters::Booster;
+use nzsc_single_player::moves::Move;
+use nzsc_single_player::io::{
+    Answer,
+    CharacterSelection,
+    BoosterSelection,
+    MoveSelection,
+    Notification,
+};
+
+/// Always chooses Ninja, Shadow, and Apocalypse (which a Ninja can't choose),
+/// and remembers how much history it was given.
+struct StubbornStrategy {
+    history_lengths: Arc<Mutex<Vec<usize>>>,
+}
+
+impl ComputerStrategy for StubbornStrategy {
+    fn choose_character(
+        &mut self,
+        _player: &CharacterlessPlayer,
+        _opponent: &CharacterlessPlayer,
+        _rules: &Rules,
+        history: &[HistoryEntry],
+        _prfg: &mut PseudorandomFloatGenerator,
+    ) -> Character {
+        self.history_lengths.lock().unwrap().push(history.len());
+        Character::Ninja
+    }
+
+    fn choose_booster(
+        &mut self,
+        _player: &BoosterlessPlayer,
+        _opponent: &BoosterlessPlayer,
+        _rules: &Rules,
+        history: &[HistoryEntry],
+        _prfg: &mut PseudorandomFloatGenerator,
+    ) -> Booster {
+        self.history_lengths.lock().unwrap().push(history.len());
+        Booster::Shadow
+    }
+
+    fn choose_move(
+        &mut self,
+        _player: &Player,
+        _opponent: &Player,
+        _rules: &Rules,
+        history: &[HistoryEntry],
+        _prfg: &mut PseudorandomFloatGenerator,
+    ) -> Move {
+        self.history_lengths.lock().unwrap().push(history.len());
+        Move::Apocalypse
+    }
+}
+
+#[test]
+fn the_computer_uses_its_strategy_and_only_chooses_legally() {
+    let strategy = StubbornStrategy {
+        history_lengths: Arc::new(Mutex::new(vec![])),
+    };
+    let mut game = SinglePlayerNZSCGame::with_strategy(1, Rules::standard(), Box::new(strategy));
+
+    let output = game.next(Answer::CharacterSelection(CharacterSelection::Character(Character::Samurai))).unwrap();
+    match output.notifications[0] {
+        Notification::CharacterSelectionAndHeadstart { computer_character, .. } => assert_eq!(computer_character, Character::Ninja),
+        ref other => panic!("Expected the characters, got {}", other),
+    }
+
+    let output = game.next(Answer::BoosterSelection(BoosterSelection::Booster(Booster::Strong))).unwrap();
+    match output.notifications[0] {
+        Notification::BoosterSelection { computer_booster, .. } => assert_eq!(computer_booster, Booster::Shadow),
+        ref other => panic!("Expected the boosters, got {}", other),
+    }
+
+    let output = game.next(Answer::MoveSelection(MoveSelection::Move(Move::Helmet))).unwrap();
+    match output.notifications[0] {
+        Notification::MoveSelectionAndOutcome { computer_move, .. } => {
+            assert_ne!(computer_move, Move::Apocalypse);
+            assert!(Character::Ninja.get_moves().contains(&computer_move) || Booster::Shadow.get_moves().contains(&computer_move));
+        },
+        ref other => panic!("Expected the moves, got {}", other),
+    }
+}
+
+#[test]
+fn strategies_are_given_the_history() {
+    let history_lengths = Arc::new(Mutex::new(vec![]));
+    let strategy = StubbornStrategy {
+        history_lengths: Arc::clone(&history_lengths),
+    };
+    let mut game = SinglePlayerNZSCGame::with_strategy(1, Rules::standard(), Box::new(strategy));
+
+    // A nonexistent character is penalized without asking the computer.
+    game.next(Answer::CharacterSelection(CharacterSelection::Nonexistent("Pirate".to_string()))).unwrap();
+    game.next(Answer::CharacterSelection(CharacterSelection::Character(Character::Samurai))).unwrap();
+    game.next(Answer::BoosterSelection(BoosterSelection::Booster(Booster::Strong))).unwrap();
+    game.next(Answer::MoveSelection(MoveSelection::Move(Move::Helmet))).unwrap();
+
+    assert_eq!(*history_lengths.lock().unwrap(), vec![1, 2, 3]);
+}
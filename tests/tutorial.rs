@@ -0,0 +1,45 @@
+extern crate nzsc_single_player;
+
+use nzsc_single_player::tutorial::{
+    TutorialNZSCGame,
+    Explanation,
+    Whose,
+};
+use nzsc_single_player::characters::Character;
+use nzsc_single_player::boosters::Booster;
+use nzsc_single_player::moves::Move;
+use nzsc_single_player::io::{
+    Answer,
+    CharacterSelection,
+    BoosterSelection,
+    MoveSelection,
+};
+
+#[test]
+fn standard_tutorial_explains_destruction_and_streaks() {
+    let mut tutorial = TutorialNZSCGame::standard(42);
+
+    let output = tutorial.next(Answer::CharacterSelection(CharacterSelection::Nonexistent("Pirate".to_string()))).unwrap();
+    assert_eq!(output.explanations, vec![Explanation::Waits { waits: 0 }]);
+
+    let output = tutorial.next(Answer::CharacterSelection(CharacterSelection::Character(Character::Ninja))).unwrap();
+    assert!(output.explanations.is_empty(), "Ninja and Zombie have no headstart");
+
+    tutorial.next(Answer::BoosterSelection(BoosterSelection::Booster(Booster::Shadow))).unwrap();
+
+    let output = tutorial.next(Answer::MoveSelection(MoveSelection::Move(Move::Kick))).unwrap();
+    assert!(output.explanations.contains(&Explanation::Destroyed {
+        whose: Whose::Human,
+        destroyer: Move::Zap,
+        destroyed_move: Move::Kick,
+    }));
+
+    let mut streak = None;
+    for _ in 0..3 {
+        let output = tutorial.next(Answer::MoveSelection(MoveSelection::Move(Move::NinjaSword))).unwrap();
+        streak = output.explanations.into_iter().find(|explanation| {
+            matches!(*explanation, Explanation::MoveStreakLimit { whose: Whose::Computer, .. })
+        });
+    }
+    assert_eq!(streak.unwrap().to_string(), "The computer has chosen Rampage 3 times in a row, so it can't choose it next time.");
+}
@@ -0,0 +1,173 @@
+extern crate nzsc_single_player;
+
+use nzsc_single_player::two_player_game::{
+    TwoPlayerNZSCGame,
+    Phase,
+};
+use nzsc_single_player::rules::Rules;
+use nzsc_single_player::characters::Character;
+use nzsc_single_player::moves::Move;
+use nzsc_single_player::errors::AnswerError;
+use nzsc_single_player::io::{
+    Answer,
+    CharacterSelection,
+    BoosterSelection,
+    MoveSelection,
+    Notification,
+    Question,
+    WhoGetsTheHeadstart,
+};
+
+fn character(character: Character) -> Answer {
+    Answer::CharacterSelection(CharacterSelection::Character(character))
+}
+
+/// Answers with the first option, or the last one if `last` is set.
+fn answer(question: Question, last: bool) -> Answer {
+    fn pick<T: Copy>(options: &[T], last: bool) -> Option<T> {
+        if last {
+            options.last().cloned()
+        } else {
+            options.first().cloned()
+        }
+    }
+
+    match question {
+        Question::ChooseCharacter { available_characters } => character(pick(&available_characters, last).unwrap()),
+        Question::ChooseBooster { available_boosters } => {
+            Answer::BoosterSelection(BoosterSelection::Booster(pick(&available_boosters, last).unwrap()))
+        },
+        Question::ChooseMove { available_moves } => match pick(&available_moves, last) {
+            Some(chosen_move) => Answer::MoveSelection(MoveSelection::Move(chosen_move)),
+            None => Answer::MoveSelection(MoveSelection::Nonexistent(String::new())),
+        },
+    }
+}
+
+#[test]
+fn both_players_answer_simultaneously_and_see_their_own_perspective() {
+    let mut game = TwoPlayerNZSCGame::default();
+    // Ninja gets a headstart against Samurai.
+    let output = game.next(character(Character::Ninja), character(Character::Samurai)).unwrap();
+
+    match output.a.notifications[0] {
+        Notification::CharacterSelectionAndHeadstart { human_character, computer_character, who_gets_the_headstart } => {
+            assert_eq!((human_character, computer_character), (Character::Ninja, Character::Samurai));
+            assert_eq!(who_gets_the_headstart, WhoGetsTheHeadstart::JustHuman);
+        },
+        ref other => panic!("Expected the characters, got {}", other),
+    }
+    match output.b.notifications[0] {
+        Notification::CharacterSelectionAndHeadstart { human_character, computer_character, who_gets_the_headstart } => {
+            assert_eq!((human_character, computer_character), (Character::Samurai, Character::Ninja));
+            assert_eq!(who_gets_the_headstart, WhoGetsTheHeadstart::JustComputer);
+        },
+        ref other => panic!("Expected the characters, got {}", other),
+    }
+
+    match game.phase {
+        Phase::BoosterChoosing { ref a, ref b } => assert_eq!((a.points, b.points), (1, 0)),
+        _ => panic!("Expected boosters to be chosen next"),
+    }
+    assert!(output.a.question.is_some() && output.b.question.is_some());
+}
+
+#[test]
+fn same_characters_are_chosen_again() {
+    let mut game = TwoPlayerNZSCGame::default();
+    let output = game.next(character(Character::Clown), character(Character::Clown)).unwrap();
+
+    for notifications in &[&output.a.notifications, &output.b.notifications] {
+        match notifications[0] {
+            Notification::SameCharacterSelection { both_character } => assert_eq!(both_character, Character::Clown),
+            ref other => panic!("Expected the same characters, got {}", other),
+        }
+    }
+    match game.phase {
+        Phase::CharacterChoosing { .. } => {},
+        _ => panic!("Expected characters to be chosen again"),
+    }
+}
+
+#[test]
+fn only_the_player_who_answered_illegally_is_penalized() {
+    let mut game = TwoPlayerNZSCGame::default();
+    let output = game.next(
+        Answer::CharacterSelection(CharacterSelection::Nonexistent("Pirate".to_string())),
+        character(Character::Zombie),
+    ).unwrap();
+
+    match output.a.notifications[0] {
+        Notification::CharacterNonexistentPenalty { ref attempted_character_name } => assert_eq!(attempted_character_name, "Pirate"),
+        ref other => panic!("Expected a's penalty, got {}", other),
+    }
+    match output.b.notifications[0] {
+        Notification::OpponentPenalty { ref penalty } => match **penalty {
+            Notification::CharacterNonexistentPenalty { .. } => {},
+            ref other => panic!("Expected a's penalty, got {}", other),
+        },
+        ref other => panic!("Expected a's penalty, got {}", other),
+    }
+    assert!(!output.a.notifications.iter().any(|notification| matches!(*notification, Notification::OpponentPenalty { .. })));
+
+    // Both players answer again, even though b's answer was legal.
+    match game.phase {
+        Phase::CharacterChoosing { ref a, ref b } => {
+            let rules = Rules::standard();
+            assert_eq!(a.waits, rules.starting_waits - rules.nonexistent_penalty);
+            assert_eq!(b.waits, rules.starting_waits);
+        },
+        _ => panic!("Expected characters to be chosen again"),
+    }
+}
+
+#[test]
+fn wrong_answer_types_are_rejected_without_changing_the_game() {
+    let mut game = TwoPlayerNZSCGame::default();
+    let result = game.next(character(Character::Ninja), Answer::MoveSelection(MoveSelection::Move(Move::Kick)));
+
+    match result {
+        Err(AnswerError::WrongAnswerType { .. }) => {},
+        Err(other) => panic!("Expected a wrong answer type, got {}", other),
+        Ok(_) => panic!("Expected b's answer to be rejected"),
+    }
+    match game.phase {
+        Phase::CharacterChoosing { ref a, ref b } => assert_eq!((a.points, b.points), (0, 0)),
+        _ => panic!("Expected the game not to change"),
+    }
+}
+
+#[test]
+fn a_full_game_ends_with_a_winner() {
+    let rules = Rules::standard();
+    let mut game = TwoPlayerNZSCGame::new(rules);
+    let mut output = game.current_output();
+
+    for _ in 0..1000 {
+        match (output.a.question.take(), output.b.question.take()) {
+            (Some(a_question), Some(b_question)) => {
+                output = game.next(answer(a_question, false), answer(b_question, true)).unwrap();
+            },
+            (None, None) => break,
+            _ => panic!("Only one player was asked a question"),
+        }
+    }
+
+    let (a_points, b_points) = match game.phase {
+        Phase::GameOver { a_points, b_points } => (a_points, b_points),
+        _ => panic!("Expected the game to be over"),
+    };
+    assert_ne!(a_points, b_points);
+    assert!(a_points.max(b_points) >= rules.points_to_win);
+
+    match (output.a.notifications.last(), output.b.notifications.last()) {
+        (
+            Some(&Notification::GameOver { human_points: a_human, computer_points: a_computer }),
+            Some(&Notification::GameOver { human_points: b_human, computer_points: b_computer }),
+        ) => {
+            assert_eq!((a_human, a_computer), (a_points, b_points));
+            assert_eq!((b_human, b_computer), (b_points, a_points));
+        },
+        _ => panic!("Expected both players to be told the game is over"),
+    }
+}
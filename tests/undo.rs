@@ -0,0 +1,156 @@
+extern crate nzsc_single_player;
+
+use nzsc_single_player::single_player_game::{
+    SinglePlayerNZSCGame,
+    UNDO_LIMIT,
+};
+use nzsc_single_player::strategies::{
+    ComputerStrategy,
+    RandomStrategy,
+};
+use nzsc_single_player::players::{
+    CharacterlessPlayer,
+    BoosterlessPlayer,
+    Player,
+};
+use nzsc_single_player::history::HistoryEntry;
+use nzsc_single_player::prfg::PseudorandomFloatGenerator;
+use nzsc_single_player::rules::Rules;
+use nzsc_single_player::errors::UndoError;
+use nzsc_single_player::characters::Character;
+use nzsc_single_player::boosters::Booster;
+use nzsc_single_player::moves::Move;
+use nzsc_single_player::io::{
+    Answer,
+    CharacterSelection,
+    BoosterSelection,
+    MoveSelection,
+    Output,
+};
+
+fn render(output: &Output) -> Vec<String> {
+    let mut lines: Vec<String> = output.notifications.iter().map(|n| n.to_string()).collect();
+    lines.extend(output.question.iter().map(|q| q.to_string()));
+
+    lines
+}
+
+fn answers() -> Vec<Answer> {
+    vec![
+        Answer::CharacterSelection(CharacterSelection::Character(Character::Samurai)),
+        Answer::CharacterSelection(CharacterSelection::Character(Character::Ninja)),
+        Answer::BoosterSelection(BoosterSelection::Booster(Booster::Strong)),
+        Answer::MoveSelection(MoveSelection::Move(Move::Helmet)),
+        Answer::MoveSelection(MoveSelection::Move(Move::Twist)),
+        Answer::MoveSelection(MoveSelection::Nonexistent("Oops".to_string())),
+    ]
+}
+
+/// Plays `answers`, undoes every accepted one, and checks that playing them again gives the same outputs.
+fn assert_undo_replays_identically(mut game: SinglePlayerNZSCGame) {
+    assert_eq!(game.undo().err(), Some(UndoError::NothingToUndo));
+
+    let mut outputs = vec![];
+    for answer in answers() {
+        if let Ok(output) = game.next(answer) {
+            outputs.push(render(&output));
+        }
+    }
+    let accepted = game.history().len();
+
+    for _ in 0..accepted {
+        game.undo().unwrap();
+    }
+    assert!(!game.can_undo());
+    assert!(game.history().is_empty());
+
+    let replayed: Vec<Vec<String>> = answers().into_iter()
+        .filter_map(|answer| game.next(answer).ok())
+        .map(|output| render(&output))
+        .collect();
+    assert_eq!(replayed, outputs);
+}
+
+#[test]
+fn undo_restores_the_game_and_random_number_generator() {
+    assert_undo_replays_identically(SinglePlayerNZSCGame::new(7, Rules::standard()));
+}
+
+#[test]
+fn only_the_last_answers_can_be_undone() {
+    // Enough points that nonexistent characters won't end the game.
+    let rules = Rules { points_to_win: 255, tiebreak_points: 254, ..Rules::standard() };
+    let mut game = SinglePlayerNZSCGame::new(7, rules);
+    let pirate = || Answer::CharacterSelection(CharacterSelection::Nonexistent("Pirate".to_string()));
+
+    for _ in 0..UNDO_LIMIT + 5 {
+        game.next(pirate()).unwrap();
+    }
+    for _ in 0..UNDO_LIMIT {
+        game.undo().unwrap();
+    }
+
+    assert!(!game.can_undo());
+    assert_eq!(game.undo().err(), Some(UndoError::NothingToUndo));
+    assert_eq!(game.history().len(), 5);
+}
+
+/// Chooses like `RandomStrategy`, but can't be saved.
+struct UnsavableStrategy;
+
+impl ComputerStrategy for UnsavableStrategy {
+    fn choose_character(
+        &mut self,
+        player: &CharacterlessPlayer,
+        opponent: &CharacterlessPlayer,
+        rules: &Rules,
+        history: &[HistoryEntry],
+        prfg: &mut PseudorandomFloatGenerator,
+    ) -> Character {
+        RandomStrategy.choose_character(player, opponent, rules, history, prfg)
+    }
+
+    fn choose_booster(
+        &mut self,
+        player: &BoosterlessPlayer,
+        opponent: &BoosterlessPlayer,
+        rules: &Rules,
+        history: &[HistoryEntry],
+        prfg: &mut PseudorandomFloatGenerator,
+    ) -> Booster {
+        RandomStrategy.choose_booster(player, opponent, rules, history, prfg)
+    }
+
+    fn choose_move(
+        &mut self,
+        player: &Player,
+        opponent: &Player,
+        rules: &Rules,
+        history: &[HistoryEntry],
+        prfg: &mut PseudorandomFloatGenerator,
+    ) -> Move {
+        RandomStrategy.choose_move(player, opponent, rules, history, prfg)
+    }
+}
+
+#[test]
+fn answers_against_unsavable_strategies_cant_be_undone() {
+    let mut game = SinglePlayerNZSCGame::with_strategy(7, Rules::standard(), Box::new(UnsavableStrategy));
+    game.next(answers().remove(0)).unwrap();
+
+    assert!(!game.can_undo());
+    assert_eq!(game.undo().err(), Some(UndoError::UnsavableStrategy));
+    assert_eq!(game.history().len(), 1);
+}
+
+#[test]
+fn undo_can_be_disabled() {
+    let mut game = SinglePlayerNZSCGame::new(7, Rules::standard());
+    game.next(answers().remove(0)).unwrap();
+
+    game.disable_undo();
+    game.next(answers().remove(2)).unwrap();
+
+    assert!(!game.can_undo());
+    assert_eq!(game.undo().err(), Some(UndoError::Disabled));
+}
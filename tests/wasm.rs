@@ -0,0 +1,58 @@
+//! Runs headlessly in Node with `wasm-pack test --node -- --features wasm`, or with
+//! `CARGO_TARGET_WASM32_UNKNOWN_UNKNOWN_RUNNER=wasm-bindgen-test-runner cargo test --target wasm32-unknown-unknown --features wasm`.
+#![cfg(all(feature = "wasm", target_arch = "wasm32"))]
+
+extern crate nzsc_single_player;
+extern crate serde_wasm_bindgen;
+extern crate wasm_bindgen;
+extern crate wasm_bindgen_test;
+
+use nzsc_single_player::wasm::WasmSinglePlayerNZSCGame;
+use nzsc_single_player::io::{
+    Answer,
+    CharacterSelection,
+    MoveSelection,
+};
+use nzsc_single_player::characters::Character;
+
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn plays_until_game_over() {
+    let mut game = WasmSinglePlayerNZSCGame::new(42);
+    assert!(game.initial_output().unwrap().is_object());
+
+    let mut finished = false;
+
+    // Once characters are chosen, "Ninja" is penalized every time, so the game ends quickly.
+    for _ in 0..100 {
+        if let Err(error) = game.next_text("Ninja") {
+            assert_eq!(error, JsValue::from_str("The game is already over."));
+            finished = true;
+            break;
+        }
+    }
+
+    assert!(finished);
+}
+
+#[wasm_bindgen_test]
+fn accepts_answer_objects() {
+    let mut game = WasmSinglePlayerNZSCGame::new(42);
+    let answer = serde_wasm_bindgen::to_value(
+        &Answer::CharacterSelection(CharacterSelection::Character(Character::Ninja))
+    ).unwrap();
+
+    assert!(game.next(answer).unwrap().is_object());
+}
+
+#[wasm_bindgen_test]
+fn rejects_answers_of_the_wrong_type() {
+    let mut game = WasmSinglePlayerNZSCGame::new(42);
+    let answer = serde_wasm_bindgen::to_value(
+        &Answer::MoveSelection(MoveSelection::Nonexistent("kick".to_string()))
+    ).unwrap();
+
+    assert!(game.next(answer).is_err());
+}